@@ -57,11 +57,11 @@ fn print_args(args: &[Value]) -> Result<Option<Value>> {
 impl EventHandler<'_> for Handler {
     fn on_scripting_method_call(
         &mut self,
-        he: bindings::HELEMENT,
+        element: &Element,
         name: &str,
         args: &[Value],
     ) -> Result<Option<Value>> {
-        let _ = he;
+        let _ = element;
 
         if name == "printArgs" {
             return print_args(args);
@@ -1,6 +1,8 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
-use crate::{args_as_raw_slice, bindings::*, utf, Error, EventGroups, Result, Value, ValueError};
+use crate::{
+    args_as_raw_slice, bindings::*, utf, DomError, Error, EventGroups, Result, Value, ValueError,
+};
 use std::mem::MaybeUninit;
 
 mod graphics;
@@ -58,6 +60,9 @@ cfg_if::cfg_if! {
     }
 }
 
+/// `SciterSortElements` comparator, returns negative, zero or positive like `strcmp`.
+type ElementComparator = Option<unsafe extern "C" fn(HELEMENT, HELEMENT, LPVOID) -> INT>;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Api<'api> {
     raw: &'api ISciterAPI,
@@ -406,7 +411,339 @@ impl<'api> Api<'api> {
             SciterSetupDebugOutput(hwnd.unwrap_or_default(), param, proc)
         )
     }
-    // --------------- before this line all methods are in order
+
+    // --------------- the DOM methods below follow the `ISciterAPI` order as well
+
+    /// Increments the element's reference count.
+    pub fn use_element(&self, he: HELEMENT) -> Result<()> {
+        call_method!(self, Sciter_UseElement(he) as SCDOM_RESULT)
+    }
+
+    /// Decrements the element's reference count.
+    pub fn unuse_element(&self, he: HELEMENT) -> Result<()> {
+        call_method!(self, Sciter_UnuseElement(he) as SCDOM_RESULT)
+    }
+
+    /// Returns the root element of the window's document, null if there is no document loaded.
+    pub fn get_root_element(&self, hwnd: HWND) -> Result<HELEMENT> {
+        dom_ret_val!(self, SciterGetRootElement(hwnd))
+    }
+
+    pub fn get_focus_element(&self, hwnd: HWND) -> Result<HELEMENT> {
+        dom_ret_val!(self, SciterGetFocusElement(hwnd))
+    }
+
+    pub fn get_children_count(&self, he: HELEMENT) -> Result<UINT> {
+        dom_ret_val!(self, SciterGetChildrenCount(he) as UINT)
+    }
+
+    /// Returns null if there is no such child.
+    pub fn get_nth_child(&self, he: HELEMENT, n: UINT) -> Result<HELEMENT> {
+        dom_ret_val!(self, SciterGetNthChild(he, n))
+    }
+
+    /// Returns null for the root element.
+    pub fn get_parent_element(&self, he: HELEMENT) -> Result<HELEMENT> {
+        dom_ret_val!(self, SciterGetParentElement(he))
+    }
+
+    /// Gets the element's inner or outer HTML as UTF-8 bytes passed to the `rcv` callback.
+    pub fn get_element_html_cb(
+        &self,
+        he: HELEMENT,
+        outer: bool,
+        rcv: Option<unsafe extern "C" fn(arg1: LPCBYTE, arg2: UINT, arg3: LPVOID)>,
+        rcv_param: LPVOID,
+    ) -> Result<()> {
+        call_method!(
+            self,
+            SciterGetElementHtmlCB(he, outer as SBOOL, rcv, rcv_param) as SCDOM_RESULT
+        )
+    }
+
+    pub fn get_element_text_cb(
+        &self,
+        he: HELEMENT,
+        rcv: Option<unsafe extern "C" fn(arg1: LPCWSTR, arg2: UINT, arg3: LPVOID)>,
+        rcv_param: LPVOID,
+    ) -> Result<()> {
+        call_method!(
+            self,
+            SciterGetElementTextCB(he, rcv, rcv_param) as SCDOM_RESULT
+        )
+    }
+
+    pub fn set_element_text(&self, he: HELEMENT, text: &str) -> Result<()> {
+        let text = utf::str_to_utf16_no_trailing_zero(text);
+        call_method!(
+            self,
+            SciterSetElementText(he, text.as_ptr(), text.len() as UINT) as SCDOM_RESULT
+        )
+    }
+
+    pub fn get_attribute_count(&self, he: HELEMENT) -> Result<UINT> {
+        dom_ret_val!(self, SciterGetAttributeCount(he) as UINT)
+    }
+
+    pub fn get_nth_attribute_name_cb(
+        &self,
+        he: HELEMENT,
+        n: UINT,
+        rcv: Option<unsafe extern "C" fn(arg1: LPCSTR, arg2: UINT, arg3: LPVOID)>,
+        rcv_param: LPVOID,
+    ) -> Result<()> {
+        call_method!(
+            self,
+            SciterGetNthAttributeNameCB(he, n, rcv, rcv_param) as SCDOM_RESULT
+        )
+    }
+
+    pub fn get_nth_attribute_value_cb(
+        &self,
+        he: HELEMENT,
+        n: UINT,
+        rcv: Option<unsafe extern "C" fn(arg1: LPCWSTR, arg2: UINT, arg3: LPVOID)>,
+        rcv_param: LPVOID,
+    ) -> Result<()> {
+        call_method!(
+            self,
+            SciterGetNthAttributeValueCB(he, n, rcv, rcv_param) as SCDOM_RESULT
+        )
+    }
+
+    /// Returns [`DomError::NotHandled`] if there is no such attribute.
+    pub fn get_attribute_by_name_cb(
+        &self,
+        he: HELEMENT,
+        name: &str,
+        rcv: Option<unsafe extern "C" fn(arg1: LPCWSTR, arg2: UINT, arg3: LPVOID)>,
+        rcv_param: LPVOID,
+    ) -> Result<()> {
+        let name = utf::str_to_utf8(name);
+        call_method!(
+            self,
+            SciterGetAttributeByNameCB(he, name.as_ptr() as LPCSTR, rcv, rcv_param) as SCDOM_RESULT
+        )
+    }
+
+    /// Sets the attribute value, `None` removes the attribute.
+    pub fn set_attribute_by_name(
+        &self,
+        he: HELEMENT,
+        name: &str,
+        value: Option<&str>,
+    ) -> Result<()> {
+        let name = utf::str_to_utf8(name);
+        let value = value.map(utf::str_to_utf16);
+        let value_ptr = value.as_ref().map_or(std::ptr::null(), |v| v.as_ptr());
+        call_method!(
+            self,
+            SciterSetAttributeByName(he, name.as_ptr() as LPCSTR, value_ptr) as SCDOM_RESULT
+        )
+    }
+
+    /// Removes all attributes from the element.
+    pub fn clear_attributes(&self, he: HELEMENT) -> Result<()> {
+        call_method!(self, SciterClearAttributes(he) as SCDOM_RESULT)
+    }
+
+    /// Index of the element in its parent's children collection.
+    pub fn get_element_index(&self, he: HELEMENT) -> Result<UINT> {
+        dom_ret_val!(self, SciterGetElementIndex(he) as UINT)
+    }
+
+    /// Returns [`DomError::NotHandled`] if there is no such style attribute.
+    pub fn get_style_attribute_cb(
+        &self,
+        he: HELEMENT,
+        name: &str,
+        rcv: Option<unsafe extern "C" fn(arg1: LPCWSTR, arg2: UINT, arg3: LPVOID)>,
+        rcv_param: LPVOID,
+    ) -> Result<()> {
+        let name = utf::str_to_utf8(name);
+        call_method!(
+            self,
+            SciterGetStyleAttributeCB(he, name.as_ptr() as LPCSTR, rcv, rcv_param) as SCDOM_RESULT
+        )
+    }
+
+    /// Sets the runtime style attribute, `None` clears it.
+    pub fn set_style_attribute(&self, he: HELEMENT, name: &str, value: Option<&str>) -> Result<()> {
+        let name = utf::str_to_utf8(name);
+        let value = value.map(utf::str_to_utf16);
+        let value_ptr = value.as_ref().map_or(std::ptr::null(), |v| v.as_ptr());
+        call_method!(
+            self,
+            SciterSetStyleAttribute(he, name.as_ptr() as LPCSTR, value_ptr) as SCDOM_RESULT
+        )
+    }
+
+    pub fn get_element_location(&self, he: HELEMENT, areas: UINT) -> Result<RECT> {
+        dom_ret_val!(self, SciterGetElementLocation(he; areas) as RECT)
+    }
+
+    pub fn scroll_to_view(&self, he: HELEMENT, flags: UINT) -> Result<()> {
+        call_method!(self, SciterScrollToView(he, flags) as SCDOM_RESULT)
+    }
+
+    /// Applies changes and refreshes the element area in its window.
+    pub fn update_element(&self, he: HELEMENT, force_render: bool) -> Result<()> {
+        call_method!(
+            self,
+            SciterUpdateElement(he, force_render as SBOOL) as SCDOM_RESULT
+        )
+    }
+
+    /// Routes all mouse events to the element until [`Api::release_capture`].
+    pub fn set_capture(&self, he: HELEMENT) -> Result<()> {
+        call_method!(self, SciterSetCapture(he) as SCDOM_RESULT)
+    }
+
+    pub fn release_capture(&self, he: HELEMENT) -> Result<()> {
+        call_method!(self, SciterReleaseCapture(he) as SCDOM_RESULT)
+    }
+
+    /// Returns the window the element belongs to, `root_window` requests the top-level one.
+    pub fn get_element_hwnd(&self, he: HELEMENT, root_window: bool) -> Result<HWND> {
+        dom_ret_val!(self, SciterGetElementHwnd(he; root_window as SBOOL) as HWND)
+    }
+
+    /// Calls the `callback` for each element matching the CSS selector(s) in the `he` subtree.
+    /// The callback should return `true` to stop the enumeration.
+    pub fn select_elements(
+        &self,
+        he: HELEMENT,
+        css_selectors: &str,
+        callback: Option<unsafe extern "C" fn(he: HELEMENT, param: LPVOID) -> SBOOL>,
+        param: LPVOID,
+    ) -> Result<()> {
+        let css_selectors = utf::str_to_utf16(css_selectors);
+        call_method!(
+            self,
+            SciterSelectElementsW(he, css_selectors.as_ptr(), callback, param) as SCDOM_RESULT
+        )
+    }
+
+    /// Finds the nearest parent (or the element itself) matching the CSS selector.
+    /// `depth` limits the number of levels to check, 0 means up to the root.
+    ///
+    /// Returns null if nothing found.
+    pub fn select_parent(&self, he: HELEMENT, selector: &str, depth: UINT) -> Result<HELEMENT> {
+        let selector = utf::str_to_utf16(selector);
+        dom_ret_val!(self, SciterSelectParentW(he, selector.as_ptr(), depth))
+    }
+
+    /// Sets the inner or outer HTML of the element, `html` is UTF-8 encoded.
+    pub fn set_element_html(
+        &self,
+        he: HELEMENT,
+        html: &[u8],
+        where_: SET_ELEMENT_HTML,
+    ) -> Result<()> {
+        call_method!(
+            self,
+            SciterSetElementHtml(he, html.as_ptr(), html.len() as UINT, where_ as UINT)
+                as SCDOM_RESULT
+        )
+    }
+
+    pub fn get_element_uid(&self, he: HELEMENT) -> Result<UINT> {
+        dom_ret_val!(self, SciterGetElementUID(he) as UINT)
+    }
+
+    pub fn get_element_by_uid(&self, hwnd: HWND, uid: UINT) -> Result<HELEMENT> {
+        dom_ret_val!(self, SciterGetElementByUID(hwnd, uid))
+    }
+
+    pub fn show_popup(&self, he: HELEMENT, anchor: HELEMENT, placement: UINT) -> Result<()> {
+        call_method!(self, SciterShowPopup(he, anchor, placement) as SCDOM_RESULT)
+    }
+
+    pub fn show_popup_at(&self, he: HELEMENT, pos: POINT, placement: UINT) -> Result<()> {
+        call_method!(self, SciterShowPopupAt(he, pos, placement) as SCDOM_RESULT)
+    }
+
+    pub fn hide_popup(&self, he: HELEMENT) -> Result<()> {
+        call_method!(self, SciterHidePopup(he) as SCDOM_RESULT)
+    }
+
+    pub fn get_element_state(&self, he: HELEMENT) -> Result<UINT> {
+        dom_ret_val!(self, SciterGetElementState(he) as UINT)
+    }
+
+    pub fn set_element_state(
+        &self,
+        he: HELEMENT,
+        to_set: UINT,
+        to_clear: UINT,
+        update_view: bool,
+    ) -> Result<()> {
+        call_method!(
+            self,
+            SciterSetElementState(he, to_set, to_clear, update_view as SBOOL) as SCDOM_RESULT
+        )
+    }
+
+    /// Creates a new element, the returned handle is already "used",
+    /// so the caller is responsible for the [`Api::unuse_element`] call.
+    pub fn create_element(&self, tag_name: &str, text: Option<&str>) -> Result<HELEMENT> {
+        let tag_name = utf::str_to_utf8(tag_name);
+        let text = text.map(utf::str_to_utf16);
+        let text_ptr = text.as_ref().map_or(std::ptr::null(), |t| t.as_ptr());
+        dom_ret_val!(
+            self,
+            SciterCreateElement(tag_name.as_ptr() as LPCSTR, text_ptr)
+        )
+    }
+
+    /// Creates a deep copy of the element, the returned handle is already "used",
+    /// so the caller is responsible for the [`Api::unuse_element`] call.
+    pub fn clone_element(&self, he: HELEMENT) -> Result<HELEMENT> {
+        dom_ret_val!(self, SciterCloneElement(he))
+    }
+
+    /// Inserts the element into the `parent` at `index`. If `index` is greater than
+    /// the number of children, the element is appended.
+    pub fn insert_element(&self, he: HELEMENT, parent: HELEMENT, index: UINT) -> Result<()> {
+        call_method!(self, SciterInsertElement(he, parent, index) as SCDOM_RESULT)
+    }
+
+    /// Takes the element out of its container (and DOM tree).
+    pub fn detach_element(&self, he: HELEMENT) -> Result<()> {
+        call_method!(self, SciterDetachElement(he) as SCDOM_RESULT)
+    }
+
+    /// Takes the element out of its container (and DOM tree) and forces destruction of all behaviors.
+    pub fn delete_element(&self, he: HELEMENT) -> Result<()> {
+        call_method!(self, SciterDeleteElement(he) as SCDOM_RESULT)
+    }
+
+    /// Starts the timer, `HANDLE_TIMER` events are delivered to the element handlers.
+    /// Zero `milliseconds` stops the timer.
+    pub fn set_timer(&self, he: HELEMENT, milliseconds: UINT, timer_id: UINT_PTR) -> Result<()> {
+        call_method!(
+            self,
+            SciterSetTimer(he, milliseconds, timer_id) as SCDOM_RESULT
+        )
+    }
+
+    pub fn detach_event_handler(
+        &self,
+        he: HELEMENT,
+        pep: LPELEMENT_EVENT_PROC,
+        tag: LPVOID,
+    ) -> Result<()> {
+        call_method!(self, SciterDetachEventHandler(he, pep, tag) as SCDOM_RESULT)
+    }
+
+    pub fn attach_event_handler(
+        &self,
+        he: HELEMENT,
+        pep: LPELEMENT_EVENT_PROC,
+        tag: LPVOID,
+    ) -> Result<()> {
+        call_method!(self, SciterAttachEventHandler(he, pep, tag) as SCDOM_RESULT)
+    }
 
     pub fn window_attach_event_handler(
         &self,
@@ -415,18 +752,374 @@ impl<'api> Api<'api> {
         tag: LPVOID,
         subscription: EventGroups,
     ) -> Result<()> {
-        let res = call_method!(
+        call_method!(
+            self,
+            SciterWindowAttachEventHandler(hwnd, pep, tag, subscription.0 as u32) as SCDOM_RESULT
+        )
+    }
+
+    pub fn window_detach_event_handler(
+        &self,
+        hwnd: HWND,
+        pep: LPELEMENT_EVENT_PROC,
+        tag: LPVOID,
+    ) -> Result<()> {
+        call_method!(
             self,
-            SciterWindowAttachEventHandler(hwnd, pep, tag, subscription.0 as u32)
+            SciterWindowDetachEventHandler(hwnd, pep, tag) as SCDOM_RESULT
+        )
+    }
+
+    /// Sends the event synchronously, returns `true` if it was handled.
+    pub fn send_event(
+        &self,
+        he: HELEMENT,
+        code: UINT,
+        source: HELEMENT,
+        reason: UINT_PTR,
+    ) -> Result<bool> {
+        dom_ret_val!(self, SciterSendEvent(he, code, source, reason) as SBOOL).map(|v| v != 0)
+    }
+
+    /// Requests the `url` data, the element receives it in a `HANDLE_DATA_ARRIVED` event.
+    pub fn request_element_data(
+        &self,
+        he: HELEMENT,
+        url: &str,
+        data_type: SciterResourceType,
+        initiator: HELEMENT,
+    ) -> Result<()> {
+        let url = utf::str_to_utf16(url);
+        call_method!(
+            self,
+            SciterRequestElementData(he, url.as_ptr(), data_type as i32 as UINT, initiator)
+                as SCDOM_RESULT
+        )
+    }
+
+    /// Sends the HTTP request, the element receives the response in a `HANDLE_DATA_ARRIVED` event.
+    pub fn http_request(
+        &self,
+        he: HELEMENT,
+        url: &str,
+        data_type: SciterResourceType,
+        request_type: REQUEST_TYPE,
+        params: &[(&str, &str)],
+    ) -> Result<()> {
+        let url = utf::str_to_utf16(url);
+        let strings: Vec<(Vec<u16>, Vec<u16>)> = params
+            .iter()
+            .map(|(name, value)| (utf::str_to_utf16(name), utf::str_to_utf16(value)))
+            .collect();
+        let mut params: Vec<REQUEST_PARAM> = strings
+            .iter()
+            .map(|(name, value)| REQUEST_PARAM {
+                name: name.as_ptr(),
+                value: value.as_ptr(),
+            })
+            .collect();
+        let params_ptr = if params.is_empty() {
+            std::ptr::null_mut()
+        } else {
+            params.as_mut_ptr()
+        };
+
+        call_method!(
+            self,
+            SciterHttpRequest(
+                he,
+                url.as_ptr(),
+                data_type as i32 as UINT,
+                request_type as i32 as UINT,
+                params_ptr,
+                params.len() as UINT
+            ) as SCDOM_RESULT
+        )
+    }
+
+    /// Returns the scroll position, the view rectangle and the content size.
+    pub fn get_scroll_info(&self, he: HELEMENT) -> Result<(POINT, RECT, SIZE)> {
+        call_method!(self, SciterGetScrollInfo as f, {
+            let mut pos = MaybeUninit::<POINT>::zeroed();
+            let mut view_rect = MaybeUninit::<RECT>::zeroed();
+            let mut content_size = MaybeUninit::<SIZE>::zeroed();
+            match f(
+                he,
+                pos.as_mut_ptr(),
+                view_rect.as_mut_ptr(),
+                content_size.as_mut_ptr(),
+            ) {
+                res if res == SCDOM_OK as i32 => Ok((
+                    pos.assume_init(),
+                    view_rect.assume_init(),
+                    content_size.assume_init(),
+                )),
+                err => Err(Error::from(DomError::from(err))),
+            }
+        })
+    }
+
+    pub fn set_scroll_pos(&self, he: HELEMENT, pos: POINT, smooth: bool) -> Result<()> {
+        call_method!(
+            self,
+            SciterSetScrollPos(he, pos, smooth as SBOOL) as SCDOM_RESULT
+        )
+    }
+
+    pub fn get_element_intrinsic_widths(&self, he: HELEMENT) -> Result<(INT, INT)> {
+        call_method!(self, SciterGetElementIntrinsicWidths as f, {
+            let mut min = MaybeUninit::<INT>::zeroed();
+            let mut max = MaybeUninit::<INT>::zeroed();
+            match f(he, min.as_mut_ptr(), max.as_mut_ptr()) {
+                res if res == SCDOM_OK as i32 => Ok((min.assume_init(), max.assume_init())),
+                err => Err(Error::from(DomError::from(err))),
+            }
+        })
+    }
+
+    pub fn get_element_intrinsic_height(&self, he: HELEMENT, for_width: INT) -> Result<INT> {
+        dom_ret_val!(self, SciterGetElementIntrinsicHeight(he, for_width) as INT)
+    }
+
+    pub fn is_element_visible(&self, he: HELEMENT) -> Result<bool> {
+        dom_ret_val!(self, SciterIsElementVisible(he) as SBOOL).map(|v| v != 0)
+    }
+
+    pub fn is_element_enabled(&self, he: HELEMENT) -> Result<bool> {
+        dom_ret_val!(self, SciterIsElementEnabled(he) as SBOOL).map(|v| v != 0)
+    }
+
+    /// Sorts the `[first, last)` range of the element children using the `cmp` function.
+    pub fn sort_elements(
+        &self,
+        he: HELEMENT,
+        first: UINT,
+        last: UINT,
+        cmp: ElementComparator,
+        cmp_param: LPVOID,
+    ) -> Result<()> {
+        call_method!(
+            self,
+            SciterSortElements(he, first, last, cmp, cmp_param) as SCDOM_RESULT
+        )
+    }
+
+    pub fn swap_elements(&self, he1: HELEMENT, he2: HELEMENT) -> Result<()> {
+        call_method!(self, SciterSwapElements(he1, he2) as SCDOM_RESULT)
+    }
+
+    /// Passes the synthetic input event through the DOM, `params` should point to
+    /// `MOUSE_PARAMS`, `KEY_PARAMS` or `FOCUS_PARAMS` according to the `event_group`.
+    /// Returns `true` if the event was processed.
+    pub fn traverse_ui_event(&self, event_group: EventGroups, params: LPVOID) -> Result<bool> {
+        dom_ret_val!(
+            self,
+            SciterTraverseUIEvent(event_group.0 as UINT, params) as SBOOL
+        )
+        .map(|v| v != 0)
+    }
+
+    pub fn call_scripting_method(&self, he: HELEMENT, name: &str, args: &[Value]) -> Result<Value> {
+        let name = utf::str_to_utf8(name);
+        let args = args_as_raw_slice(args);
+        let mut ret_val = Value::new();
+        call_method!(
+            self,
+            SciterCallScriptingMethod(
+                he,
+                name.as_ptr() as LPCSTR,
+                args.as_ptr(),
+                args.len() as UINT,
+                &mut ret_val.0
+            ) as SCDOM_RESULT
         )?;
+        Ok(ret_val)
+    }
 
-        match res as u32 {
-            SCDOM_OK => Ok(()),
-            _ => todo!(),
-        }
+    pub fn call_scripting_function(
+        &self,
+        he: HELEMENT,
+        name: &str,
+        args: &[Value],
+    ) -> Result<Value> {
+        let name = utf::str_to_utf8(name);
+        let args = args_as_raw_slice(args);
+        let mut ret_val = Value::new();
+        call_method!(
+            self,
+            SciterCallScriptingFunction(
+                he,
+                name.as_ptr() as LPCSTR,
+                args.as_ptr(),
+                args.len() as UINT,
+                &mut ret_val.0
+            ) as SCDOM_RESULT
+        )?;
+        Ok(ret_val)
+    }
+
+    pub fn eval_element_script(&self, he: HELEMENT, script: &str) -> Result<Value> {
+        let script = utf::str_to_utf16_no_trailing_zero(script);
+        let mut ret_val = Value::new();
+        call_method!(
+            self,
+            SciterEvalElementScript(he, script.as_ptr(), script.len() as UINT, &mut ret_val.0)
+                as SCDOM_RESULT
+        )?;
+        Ok(ret_val)
+    }
+
+    /// Binds the child window to the element, Sciter keeps its position and size
+    /// in sync with the element box. Null `hwnd` detaches the window.
+    pub fn attach_hwnd_to_element(&self, he: HELEMENT, hwnd: HWND) -> Result<()> {
+        call_method!(self, SciterAttachHwndToElement(he, hwnd) as SCDOM_RESULT)
+    }
+
+    pub fn get_value(&self, he: HELEMENT) -> Result<Value> {
+        let mut value = Value::new();
+        call_method!(self, SciterGetValue(he, &mut value.0) as SCDOM_RESULT)?;
+        Ok(value)
     }
 
-    // --------------- after this line all methods are in order
+    pub fn set_value(&self, he: HELEMENT, value: &Value) -> Result<()> {
+        call_method!(self, SciterSetValue(he, &value.0) as SCDOM_RESULT)
+    }
+
+    pub fn get_expando(&self, he: HELEMENT, force_creation: bool) -> Result<Value> {
+        let mut value = Value::new();
+        call_method!(
+            self,
+            SciterGetExpando(he, &mut value.0, force_creation as SBOOL) as SCDOM_RESULT
+        )?;
+        Ok(value)
+    }
+
+    pub fn get_object(&self, he: HELEMENT, force_creation: bool) -> Result<Value> {
+        let mut value = Value::new();
+        call_method!(
+            self,
+            SciterGetObject(
+                he,
+                &mut value.0 as *mut VALUE as *mut _,
+                force_creation as SBOOL
+            ) as SCDOM_RESULT
+        )?;
+        Ok(value)
+    }
+
+    pub fn get_highlighted_element(&self, hwnd: HWND) -> Result<HELEMENT> {
+        dom_ret_val!(self, SciterGetHighlightedElement(hwnd))
+    }
+
+    pub fn set_highlighted_element(&self, hwnd: HWND, he: HELEMENT) -> Result<()> {
+        call_method!(self, SciterSetHighlightedElement(hwnd, he) as SCDOM_RESULT)
+    }
+
+    pub fn node_add_ref(&self, hn: HNODE) -> Result<()> {
+        call_method!(self, SciterNodeAddRef(hn) as SCDOM_RESULT)
+    }
+
+    pub fn node_release(&self, hn: HNODE) -> Result<()> {
+        call_method!(self, SciterNodeRelease(hn) as SCDOM_RESULT)
+    }
+
+    pub fn node_cast_from_element(&self, he: HELEMENT) -> Result<HNODE> {
+        dom_ret_val!(self, SciterNodeCastFromElement(he) as HNODE)
+    }
+
+    pub fn node_cast_to_element(&self, hn: HNODE) -> Result<HELEMENT> {
+        dom_ret_val!(self, SciterNodeCastToElement(hn))
+    }
+
+    /// Returns null if there are no children.
+    pub fn node_first_child(&self, hn: HNODE) -> Result<HNODE> {
+        dom_ret_val!(self, SciterNodeFirstChild(hn) as HNODE)
+    }
+
+    /// Returns null if there are no children.
+    pub fn node_last_child(&self, hn: HNODE) -> Result<HNODE> {
+        dom_ret_val!(self, SciterNodeLastChild(hn) as HNODE)
+    }
+
+    /// Returns null for the last node.
+    pub fn node_next_sibling(&self, hn: HNODE) -> Result<HNODE> {
+        dom_ret_val!(self, SciterNodeNextSibling(hn) as HNODE)
+    }
+
+    /// Returns null for the first node.
+    pub fn node_prev_sibling(&self, hn: HNODE) -> Result<HNODE> {
+        dom_ret_val!(self, SciterNodePrevSibling(hn) as HNODE)
+    }
+
+    /// Returns null for detached nodes.
+    pub fn node_parent(&self, hn: HNODE) -> Result<HELEMENT> {
+        dom_ret_val!(self, SciterNodeParent(hn))
+    }
+
+    pub fn node_nth_child(&self, hn: HNODE, n: UINT) -> Result<HNODE> {
+        dom_ret_val!(self, SciterNodeNthChild(hn, n) as HNODE)
+    }
+
+    pub fn node_children_count(&self, hn: HNODE) -> Result<UINT> {
+        dom_ret_val!(self, SciterNodeChildrenCount(hn) as UINT)
+    }
+
+    /// Returns one of `NODE_TYPE` values.
+    pub fn node_type(&self, hn: HNODE) -> Result<UINT> {
+        dom_ret_val!(self, SciterNodeType(hn) as UINT)
+    }
+
+    pub fn node_get_text(
+        &self,
+        hn: HNODE,
+        rcv: Option<unsafe extern "C" fn(arg1: LPCWSTR, arg2: UINT, arg3: LPVOID)>,
+        rcv_param: LPVOID,
+    ) -> Result<()> {
+        call_method!(self, SciterNodeGetText(hn, rcv, rcv_param) as SCDOM_RESULT)
+    }
+
+    pub fn node_set_text(&self, hn: HNODE, text: &str) -> Result<()> {
+        let text = utf::str_to_utf16_no_trailing_zero(text);
+        call_method!(
+            self,
+            SciterNodeSetText(hn, text.as_ptr(), text.len() as UINT) as SCDOM_RESULT
+        )
+    }
+
+    pub fn node_insert(&self, hn: HNODE, where_: NODE_INS_TARGET, what: HNODE) -> Result<()> {
+        call_method!(
+            self,
+            SciterNodeInsert(hn, where_ as UINT, what) as SCDOM_RESULT
+        )
+    }
+
+    /// Detaches the node from the DOM tree, `finalize` also destroys it.
+    pub fn node_remove(&self, hn: HNODE, finalize: bool) -> Result<()> {
+        call_method!(
+            self,
+            SciterNodeRemove(hn, finalize as SBOOL) as SCDOM_RESULT
+        )
+    }
+
+    /// Creates a new text node, the returned handle is already referenced,
+    /// so the caller is responsible for the [`Api::node_release`] call.
+    pub fn create_text_node(&self, text: &str) -> Result<HNODE> {
+        let text = utf::str_to_utf16_no_trailing_zero(text);
+        dom_ret_val!(
+            self,
+            SciterCreateTextNode(text.as_ptr(), text.len() as UINT) as HNODE
+        )
+    }
+
+    /// Creates a new comment node, the returned handle is already referenced,
+    /// so the caller is responsible for the [`Api::node_release`] call.
+    pub fn create_comment_node(&self, text: &str) -> Result<HNODE> {
+        let text = utf::str_to_utf16_no_trailing_zero(text);
+        dom_ret_val!(
+            self,
+            SciterCreateCommentNode(text.as_ptr(), text.len() as UINT) as HNODE
+        )
+    }
 
     /// Initialize VALUE storage
     /// This call has to be made before passing VALUE* to any other functions
@@ -754,6 +1447,11 @@ impl<'api> Api<'api> {
         call_method!(self, SciterCloseArchive(har) as bool)
     }
 
+    /// Sends or posts the event, returns `true` if it was handled (always `false` for posted events).
+    pub fn fire_event(&self, params: &BEHAVIOR_EVENT_PARAMS, post: bool) -> Result<bool> {
+        dom_ret_val!(self, SciterFireEvent(params, post as SBOOL) as SBOOL).map(|v| v != 0)
+    }
+
     // --------------- before this line methods are in order until the similar comment
 
     pub fn post_callback(
@@ -795,6 +1493,36 @@ impl<'api> Api<'api> {
         call_method!(self, SciterSetGlobalAsset(pass) as bool)
     }
 
+    /// Returns the element if the value is a wrapped DOM element, the handle is not "used".
+    pub fn element_unwrap(&self, value: &VALUE) -> Result<Option<HELEMENT>> {
+        call_method!(self, SciterElementUnwrap as f, {
+            let mut he: HELEMENT = std::ptr::null_mut();
+            match f(value, &mut he) {
+                0 => Ok(None),
+                _ => Ok(Some(he)),
+            }
+        })
+    }
+
+    pub fn element_wrap(&self, value: &mut VALUE, he: HELEMENT) -> Result<bool> {
+        call_method!(self, SciterElementWrap(value, he) as bool)
+    }
+
+    /// Returns the node if the value is a wrapped DOM node, the handle is not referenced.
+    pub fn node_unwrap(&self, value: &VALUE) -> Result<Option<HNODE>> {
+        call_method!(self, SciterNodeUnwrap as f, {
+            let mut hn: HNODE = std::ptr::null_mut();
+            match f(value, &mut hn) {
+                0 => Ok(None),
+                _ => Ok(Some(hn)),
+            }
+        })
+    }
+
+    pub fn node_wrap(&self, value: &mut VALUE, hn: HNODE) -> Result<bool> {
+        call_method!(self, SciterNodeWrap(value, hn) as bool)
+    }
+
     pub fn release_global_asset(&self, pass: *mut som_asset_t) -> Result<bool> {
         call_method!(self, SciterReleaseGlobalAsset(pass) as bool)
     }
@@ -892,6 +1620,12 @@ macro_rules! call_method {
         }
     };
 
+    ($self:ident, $name:ident($( $arg:expr ),*) as SCDOM_RESULT) => {
+        match call_method!($self, $name($($arg),*))? {
+            res if res == crate::bindings::SCDOM_OK as i32 => Ok(()),
+            err => Err(crate::Error::from(crate::DomError::from(err)))
+        }
+    };
 }
 
 macro_rules! value_impl_set {
@@ -933,7 +1667,32 @@ macro_rules! value_ret_val {
     };
 }
 
+macro_rules! dom_ret_val {
+    ($self:ident, $name:ident ( $( $arg1:expr ),* ; $( $arg2:expr ),* ) as $type:ty) => {
+        call_method!($self, $name as f, {
+            let mut ret_val = MaybeUninit::<$type>::zeroed();
+            match f($($arg1),*, ret_val.as_mut_ptr(), $($arg2),*) {
+                res if res == SCDOM_OK as i32 => Ok(ret_val.assume_init()),
+                err => Err(Error::from(DomError::from(err))),
+            }
+        })
+    };
+
+    ($self:ident, $name:ident ( $( $arg1:expr ),* ; $( $arg2:expr ),* ) ) => {
+        dom_ret_val!($self, $name ($($arg1),* ; $($arg2),*) as HELEMENT)
+    };
+
+    ($self:ident, $name:ident ( $( $arg:expr ),* ) ) => {
+        dom_ret_val!($self, $name ($($arg),* ; ) as HELEMENT)
+    };
+
+    ($self:ident, $name:ident ( $( $arg:expr ),* ) as $type:ty ) => {
+        dom_ret_val!($self, $name ($($arg),* ; ) as $type)
+    };
+}
+
 // to make visible in submodules and above definitions
 use call_method;
+use dom_ret_val;
 use value_impl_set;
 use value_ret_val;
@@ -65,21 +65,21 @@ cfg_if::cfg_if! {
     else {
         pub type HWND = isize;
 
-        #[derive(Default, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
         #[repr(C)]
         pub struct POINT {
             pub x: i32,
             pub y: i32,
         }
 
-        #[derive(Default, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
         #[repr(C)]
         pub struct SIZE {
             pub cx: i32,
             pub cy: i32,
         }
 
-        #[derive(Default, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
         #[repr(C)]
         pub struct RECT {
             pub left: i32,
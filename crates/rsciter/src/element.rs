@@ -0,0 +1,873 @@
+use std::{
+    any::Any,
+    cmp::Ordering,
+    fmt::Debug,
+    ops::{Bound, RangeBounds},
+    panic::AssertUnwindSafe,
+    time::Duration,
+};
+
+use crate::{
+    api::sapi, bindings::*, utf, BehaviorEvent, DomError, Error, EventHandler, EventParams, Result,
+    Value, WindowHandle,
+};
+
+mod area;
+mod handler;
+mod id;
+mod popup;
+mod request;
+mod scroll;
+mod state;
+mod timer;
+
+pub use area::{ElementArea, RelativeTo};
+pub use handler::HandlerGuard;
+pub use id::ElementId;
+pub use popup::*;
+pub use request::*;
+pub use scroll::*;
+pub use state::*;
+pub use timer::TimerGuard;
+
+/// A reference counted handle to a DOM element.
+///
+/// Cloning increments the element's reference count, dropping decrements it.
+///
+/// Elements passed to window level [`EventHandler`]s may wrap the null handle,
+/// e.g. on attach before the document is loaded, check it with [`Element::as_raw`].
+#[repr(transparent)]
+pub struct Element(HELEMENT);
+
+impl Element {
+    /// Wraps the raw handle, incrementing its reference count.
+    pub fn from_raw(he: HELEMENT) -> Result<Self> {
+        Self::wrap(he)?.ok_or(Error::DomError(DomError::InvalidHandle))
+    }
+
+    /// Same as [`Element::from_raw`], but returns `None` for the null handle.
+    pub(crate) fn wrap(he: HELEMENT) -> Result<Option<Self>> {
+        if he.is_null() {
+            return Ok(None);
+        }
+
+        sapi()?.use_element(he)?;
+        Ok(Some(Self(he)))
+    }
+
+    /// Takes the ownership of the already "used" handle, e.g. returned by `SciterCreateElement`.
+    fn wrap_owned(he: HELEMENT) -> Result<Self> {
+        if he.is_null() {
+            return Err(Error::DomError(DomError::InvalidHandle));
+        }
+
+        Ok(Self(he))
+    }
+
+    /// Creates a new detached element, `text` is optional text content.
+    pub fn create(tag: &str, text: Option<&str>) -> Result<Self> {
+        let he = sapi()?.create_element(tag, text)?;
+        Self::wrap_owned(he)
+    }
+
+    /// Returns the root element of the window's document, `None` if there is no document loaded.
+    pub fn root(window: WindowHandle) -> Result<Option<Self>> {
+        let he = sapi()?.get_root_element(window.into())?;
+        Self::wrap(he)
+    }
+
+    /// Returns the underlying handle, the reference count is not affected.
+    pub fn as_raw(&self) -> HELEMENT {
+        self.0
+    }
+
+    /// Returns the parent element, `None` for the root or detached elements.
+    pub fn parent(&self) -> Result<Option<Element>> {
+        let he = sapi()?.get_parent_element(self.0)?;
+        Self::wrap(he)
+    }
+
+    /// Index of the element in its parent's children collection.
+    pub fn index(&self) -> Result<usize> {
+        sapi()?.get_element_index(self.0).map(|n| n as usize)
+    }
+
+    pub fn children_count(&self) -> Result<usize> {
+        sapi()?.get_children_count(self.0).map(|n| n as usize)
+    }
+
+    /// Returns the nth child element, `None` if there is no such child.
+    pub fn child(&self, n: usize) -> Result<Option<Element>> {
+        let he = sapi()?.get_nth_child(self.0, n as UINT)?;
+        Self::wrap(he)
+    }
+
+    /// Returns all child elements.
+    pub fn children(&self) -> Result<impl Iterator<Item = Element>> {
+        let api = sapi()?;
+        let count = api.get_children_count(self.0)?;
+        let mut children = Vec::with_capacity(count as usize);
+        for n in 0..count {
+            if let Some(child) = Self::wrap(api.get_nth_child(self.0, n)?)? {
+                children.push(child);
+            }
+        }
+
+        Ok(children.into_iter())
+    }
+
+    /// Appends the element as the last child.
+    pub fn append(&self, child: &Element) -> Result<()> {
+        sapi()?.insert_element(child.0, self.0, APPEND_INDEX)
+    }
+
+    /// Inserts the element at `index`, appends if `index` is greater than the number of children.
+    pub fn insert_at(&self, child: &Element, index: usize) -> Result<()> {
+        let index = UINT::try_from(index).unwrap_or(APPEND_INDEX);
+        sapi()?.insert_element(child.0, self.0, index)
+    }
+
+    /// Takes the element out of the DOM tree, it can be inserted again later.
+    pub fn detach(&self) -> Result<()> {
+        sapi()?.detach_element(self.0)
+    }
+
+    /// Takes the element out of the DOM tree and destroys all its behaviors.
+    pub fn remove(self) -> Result<()> {
+        sapi()?.delete_element(self.0)
+    }
+
+    /// Creates a deep copy of the element, the copy is not attached to the DOM tree.
+    pub fn clone_deep(&self) -> Result<Element> {
+        let he = sapi()?.clone_element(self.0)?;
+        Self::wrap_owned(he)
+    }
+
+    /// Sorts the children in `range` using the `cmp` function.
+    ///
+    /// A panic in `cmp` stops the comparisons and is resumed after Sciter returns.
+    pub fn sort_children(
+        &self,
+        range: impl RangeBounds<usize>,
+        cmp: impl FnMut(&Element, &Element) -> Ordering,
+    ) -> Result<()> {
+        let (first, last) = children_range(range, self.children_count()?)?;
+
+        let mut state = SortState {
+            cmp: Box::new(cmp),
+            panic: None,
+        };
+        let ptr = &mut state as *mut _;
+        let res = sapi()?.sort_elements(self.0, first, last, Some(sort_thunk), ptr as LPVOID);
+
+        if let Some(panic) = state.panic {
+            std::panic::resume_unwind(panic);
+        }
+
+        res
+    }
+
+    /// Swaps the elements positions in the DOM tree.
+    pub fn swap(&self, other: &Element) -> Result<()> {
+        sapi()?.swap_elements(self.0, other.0)
+    }
+
+    /// Returns all elements in the subtree matching the CSS selector(s).
+    pub fn select(&self, css: &str) -> Result<impl Iterator<Item = Element>> {
+        let mut elements = Vec::new();
+        self.select_impl(css, |element| {
+            elements.push(element);
+            true
+        })?;
+
+        Ok(elements.into_iter())
+    }
+
+    /// Returns the first element in the subtree matching the CSS selector(s).
+    pub fn select_first(&self, css: &str) -> Result<Option<Element>> {
+        let mut first = None;
+        self.select_impl(css, |element| {
+            first = Some(element);
+            false
+        })?;
+
+        Ok(first)
+    }
+
+    /// Returns the nearest element matching the CSS selector, starting from the element itself
+    /// and going up to the root.
+    pub fn closest(&self, css: &str) -> Result<Option<Element>> {
+        let he = sapi()?.select_parent(self.0, css, 0)?;
+        Self::wrap(he)
+    }
+
+    /// Returns the attribute value, `None` if there is no such attribute.
+    pub fn attr(&self, name: &str) -> Result<Option<String>> {
+        let mut value = String::new();
+        let res = sapi()?.get_attribute_by_name_cb(
+            self.0,
+            name,
+            Some(utf::wstr_thunk),
+            &mut value as *mut _ as _,
+        );
+
+        match res {
+            Ok(()) => Ok(Some(value)),
+            Err(Error::DomError(DomError::NotHandled)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn set_attr(&self, name: &str, value: &str) -> Result<()> {
+        sapi()?.set_attribute_by_name(self.0, name, Some(value))
+    }
+
+    pub fn remove_attr(&self, name: &str) -> Result<()> {
+        sapi()?.set_attribute_by_name(self.0, name, None)
+    }
+
+    /// Removes all the element's attributes.
+    pub fn clear_attrs(&self) -> Result<()> {
+        sapi()?.clear_attributes(self.0)
+    }
+
+    /// Number of the element's attributes.
+    pub fn attr_count(&self) -> Result<usize> {
+        sapi()?.get_attribute_count(self.0).map(|n| n as usize)
+    }
+
+    /// Returns all `(name, value)` attribute pairs.
+    pub fn attributes(&self) -> Result<impl Iterator<Item = (String, String)>> {
+        let api = sapi()?;
+        let count = api.get_attribute_count(self.0)?;
+        let mut attributes = Vec::with_capacity(count as usize);
+        for n in 0..count {
+            let mut name = String::new();
+            api.get_nth_attribute_name_cb(
+                self.0,
+                n,
+                Some(utf::str_thunk),
+                &mut name as *mut _ as _,
+            )?;
+
+            let mut value = String::new();
+            api.get_nth_attribute_value_cb(
+                self.0,
+                n,
+                Some(utf::wstr_thunk),
+                &mut value as *mut _ as _,
+            )?;
+
+            attributes.push((name, value));
+        }
+
+        Ok(attributes.into_iter())
+    }
+
+    /// Returns the element's text content.
+    pub fn text(&self) -> Result<String> {
+        let mut text = String::new();
+        sapi()?.get_element_text_cb(self.0, Some(utf::wstr_thunk), &mut text as *mut _ as _)?;
+        Ok(text)
+    }
+
+    /// Replaces the element's content with the text.
+    pub fn set_text(&self, text: &str) -> Result<()> {
+        sapi()?.set_element_text(self.0, text)
+    }
+
+    /// Returns the element's inner HTML, or the outer one if `outer` is `true`.
+    pub fn html(&self, outer: bool) -> Result<String> {
+        let mut html = String::new();
+        sapi()?.get_element_html_cb(
+            self.0,
+            outer,
+            Some(utf::utf8_thunk),
+            &mut html as *mut _ as _,
+        )?;
+        Ok(html)
+    }
+
+    /// Inserts or replaces the HTML, `html` is UTF-8 encoded.
+    pub fn set_html(&self, html: &[u8], mode: SetHtmlMode) -> Result<()> {
+        sapi()?.set_element_html(self.0, html, mode.into())
+    }
+
+    /// Returns the runtime style attribute value, `None` if it is not set.
+    pub fn style(&self, name: &str) -> Result<Option<String>> {
+        let mut value = String::new();
+        let res = sapi()?.get_style_attribute_cb(
+            self.0,
+            name,
+            Some(utf::wstr_thunk),
+            &mut value as *mut _ as _,
+        );
+
+        match res {
+            Ok(()) => Ok(Some(value)),
+            Err(Error::DomError(DomError::NotHandled)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn set_style(&self, name: &str, value: &str) -> Result<()> {
+        sapi()?.set_style_attribute(self.0, name, Some(value))
+    }
+
+    pub fn remove_style(&self, name: &str) -> Result<()> {
+        sapi()?.set_style_attribute(self.0, name, None)
+    }
+
+    /// Sets several style attributes and updates the element once.
+    pub fn set_styles(&self, styles: &[(&str, &str)]) -> Result<()> {
+        let api = sapi()?;
+        for (name, value) in styles {
+            api.set_style_attribute(self.0, name, Some(value))?;
+        }
+
+        api.update_element(self.0, false)
+    }
+
+    /// Applies pending changes and refreshes the element area,
+    /// `force_render` makes the window update immediately.
+    pub fn update(&self, force_render: bool) -> Result<()> {
+        sapi()?.update_element(self.0, force_render)
+    }
+
+    pub fn state(&self) -> Result<ElementState> {
+        sapi()?.get_element_state(self.0).map(ElementState)
+    }
+
+    /// Sets the `to_set` flags and clears the `to_clear` ones,
+    /// `update` refreshes the element view to reflect the new state.
+    pub fn set_state(
+        &self,
+        to_set: ElementState,
+        to_clear: ElementState,
+        update: bool,
+    ) -> Result<()> {
+        sapi()?.set_element_state(self.0, to_set.0, to_clear.0, update)
+    }
+
+    /// Returns `true` if all the `flags` are set.
+    pub fn has_state(&self, flags: ElementState) -> Result<bool> {
+        self.state().map(|state| state.contains(flags))
+    }
+
+    /// Moves the input focus to the element.
+    pub fn focus(&self) -> Result<()> {
+        self.set_state(ElementState::FOCUS, ElementState::empty(), true)
+    }
+
+    /// Routes all mouse events to the element, even if the cursor is outside of it,
+    /// until [`Element::release_capture`] is called.
+    pub fn set_capture(&self) -> Result<()> {
+        sapi()?.set_capture(self.0)
+    }
+
+    pub fn release_capture(&self) -> Result<()> {
+        sapi()?.release_capture(self.0)
+    }
+
+    /// Returns the `area` rectangle of the element relative to `relative_to`, in physical pixels.
+    pub fn location(&self, area: ElementArea, relative_to: RelativeTo) -> Result<RECT> {
+        sapi()?.get_element_location(self.0, area::area_bits(area, relative_to))
+    }
+
+    /// Returns `true` if the element and all its containers are visible.
+    pub fn is_visible(&self) -> Result<bool> {
+        sapi()?.is_element_visible(self.0)
+    }
+
+    /// Returns `true` if the element and all its containers are not `:disabled`.
+    pub fn is_enabled(&self) -> Result<bool> {
+        sapi()?.is_element_enabled(self.0)
+    }
+
+    /// Returns the min-intrinsic and max-intrinsic widths of the element.
+    pub fn intrinsic_widths(&self) -> Result<(i32, i32)> {
+        sapi()?.get_element_intrinsic_widths(self.0)
+    }
+
+    /// Returns the min-intrinsic height of the element for the given `width`.
+    pub fn intrinsic_height(&self, width: i32) -> Result<i32> {
+        sapi()?.get_element_intrinsic_height(self.0, width)
+    }
+
+    pub fn scroll_info(&self) -> Result<ScrollInfo> {
+        let (pos, view_rect, content_size) = sapi()?.get_scroll_info(self.0)?;
+        Ok(ScrollInfo {
+            pos,
+            view_rect,
+            content_size,
+        })
+    }
+
+    /// Scrolls the element content to `pos`, `smooth` animates the scrolling.
+    pub fn scroll_to(&self, pos: POINT, smooth: bool) -> Result<()> {
+        sapi()?.set_scroll_pos(self.0, pos, smooth)
+    }
+
+    /// Scrolls the containers to make the element visible.
+    pub fn scroll_into_view(&self, flags: ScrollFlags) -> Result<()> {
+        sapi()?.scroll_to_view(self.0, flags.0 as UINT)
+    }
+
+    /// Shows this element as a popup relative to the `anchor` element.
+    pub fn show_popup(&self, anchor: &Element, placement: PopupPlacement) -> Result<()> {
+        sapi()?.show_popup(self.0, anchor.0, placement as UINT)
+    }
+
+    /// Shows this element as a popup at `pos`, relative to the window origin.
+    /// The `placement` tells which point of the popup is put at `pos`.
+    pub fn show_popup_at(&self, pos: POINT, placement: PopupPlacement) -> Result<()> {
+        sapi()?.show_popup_at(self.0, pos, placement as UINT)
+    }
+
+    pub fn hide_popup(&self) -> Result<()> {
+        sapi()?.hide_popup(self.0)
+    }
+
+    /// Returns the value of the element: text of an `<input>`, the selected option of a `<select>`,
+    /// a map of named values for a `<form>`, etc.
+    pub fn value(&self) -> Result<Value> {
+        sapi()?.get_value(self.0)
+    }
+
+    pub fn set_value(&self, value: &Value) -> Result<()> {
+        sapi()?.set_value(self.0, value)
+    }
+
+    /// Calls the method of the element's script object, e.g. a component method.
+    pub fn call_method(&self, name: &str, args: &[Value]) -> Result<Value> {
+        sapi()?
+            .call_scripting_method(self.0, name, args)
+            .and_then(WindowHandle::check_error_string)
+    }
+
+    /// Calls the global or namespace function `name` in the context of the element's document.
+    pub fn call_function(&self, name: &str, args: &[Value]) -> Result<Value> {
+        sapi()?
+            .call_scripting_function(self.0, name, args)
+            .and_then(WindowHandle::check_error_string)
+    }
+
+    /// Evaluates the `script` with `this` set to the element.
+    pub fn eval(&self, script: &str) -> Result<Value> {
+        sapi()?
+            .eval_element_script(self.0, script)
+            .and_then(WindowHandle::check_error_string)
+    }
+
+    /// Returns the expando object of the element, i.e. the object holding the properties
+    /// assigned to the element in script. The object is created if it does not exist yet.
+    pub fn expando(&self) -> Result<Value> {
+        sapi()?.get_expando(self.0, true)
+    }
+
+    /// Returns the script object of the element, the same object as `this` in its script methods.
+    pub fn script_object(&self) -> Result<Value> {
+        sapi()?.get_object(self.0, true)
+    }
+
+    /// Returns the window the element belongs to.
+    pub fn window(&self) -> Result<WindowHandle> {
+        sapi()?
+            .get_element_hwnd(self.0, false)
+            .map(WindowHandle::from)
+    }
+
+    /// Returns the top-level window of the element.
+    pub fn root_window(&self) -> Result<WindowHandle> {
+        sapi()?
+            .get_element_hwnd(self.0, true)
+            .map(WindowHandle::from)
+    }
+
+    /// Binds the native child `window` to the element, the window follows the element's position and size.
+    ///
+    /// The window should be created as a child one, e.g. with [`crate::WindowFlags::CHILD`].
+    pub fn attach_window(&self, window: WindowHandle) -> Result<()> {
+        sapi()?.attach_hwnd_to_element(self.0, window.into())
+    }
+
+    /// Unbinds the window attached by [`Element::attach_window`].
+    pub fn detach_window(&self) -> Result<()> {
+        sapi()?.attach_hwnd_to_element(self.0, HWND::default())
+    }
+
+    /// Returns the weak identifier of the element, see [`ElementId`].
+    pub fn id(&self) -> Result<ElementId> {
+        let uid = sapi()?.get_element_uid(self.0)?;
+        Ok(ElementId::new(self.root_window()?, uid))
+    }
+
+    /// Asks the element to load the `url`, the data is delivered to the element's handlers
+    /// in [`EventHandler::on_data`], see [`DataArrived`].
+    pub fn request_data(
+        &self,
+        url: &str,
+        data_type: SciterResourceType,
+        initiator: Option<&Element>,
+    ) -> Result<()> {
+        let initiator = initiator.map_or(std::ptr::null_mut(), |initiator| initiator.0);
+        sapi()?.request_element_data(self.0, url, data_type, initiator)
+    }
+
+    /// Sends the HTTP request with the `params` on behalf of the element,
+    /// the response is delivered like for [`Element::request_data`].
+    pub fn http_request(
+        &self,
+        url: &str,
+        data_type: SciterResourceType,
+        request_type: RequestType,
+        params: &[(&str, &str)],
+    ) -> Result<()> {
+        sapi()?.http_request(self.0, url, data_type, request_type.into(), params)
+    }
+
+    /// Attaches the `handler` to this element, it receives the events of the element and its subtree.
+    ///
+    /// The handler stays attached while the returned guard is alive.
+    pub fn attach_handler(&self, handler: impl for<'s> EventHandler<'s>) -> Result<HandlerGuard> {
+        HandlerGuard::attach(self, handler)
+    }
+
+    /// Sends the event to the element synchronously, returns `true` if the event was handled.
+    pub fn fire_event(&self, params: impl Into<EventParams>) -> Result<bool> {
+        self.fire_impl(params.into(), false)
+    }
+
+    /// Sends the event without payload to the element synchronously, sinking and bubbling it
+    /// through the parents, returns `true` if the event was handled.
+    ///
+    /// `source` defaults to the element itself. The name of [`BehaviorEvent::Custom`] events is
+    /// not delivered, use [`Element::fire_event`] for them.
+    pub fn send_event(
+        &self,
+        event: BehaviorEvent,
+        source: Option<&Element>,
+        reason: usize,
+    ) -> Result<bool> {
+        let source = source.map_or(self.0, |source| source.0);
+        sapi()?.send_event(self.0, event.code(), source, reason as UINT_PTR)
+    }
+
+    /// Posts the event to the element, it gets dispatched asynchronously.
+    pub fn post_event(&self, params: impl Into<EventParams>) -> Result<()> {
+        self.fire_impl(params.into(), true).map(|_| ())
+    }
+
+    /// Sends the custom event `name` with the `data` payload, script can handle it
+    /// with `element.addEventListener(name, ...)`.
+    pub fn dispatch(&self, name: &str, data: Value) -> Result<bool> {
+        self.fire_event(EventParams::new(BehaviorEvent::custom(name)).with_data(data))
+    }
+
+    /// Starts the timer calling `callback` every `interval` on the UI thread
+    /// until it returns `false` or the returned guard is dropped.
+    ///
+    /// The `id` distinguishes timers of the same element and must be unique among its live guards,
+    /// starting a timer with the `id` already in use fails with [`DomError::InvalidParameter`].
+    pub fn set_timer(
+        &self,
+        interval: Duration,
+        id: usize,
+        callback: impl FnMut() -> bool + 'static,
+    ) -> Result<TimerGuard> {
+        TimerGuard::start(self, interval, id, callback)
+    }
+
+    fn fire_impl(&self, params: EventParams, post: bool) -> Result<bool> {
+        let (raw, _name) = raw_event_params(self.0, &params);
+        sapi()?.fire_event(&raw, post)
+    }
+
+    fn select_impl(&self, css: &str, callback: impl FnMut(Element) -> bool) -> Result<()> {
+        let mut state = SelectState {
+            callback: Box::new(callback),
+        };
+        let ptr = &mut state as *mut _;
+        sapi()?.select_elements(self.0, css, Some(select_thunk), ptr as LPVOID)
+    }
+}
+
+// the same value as sciter-x-dom.hpp uses for `element::append`
+const APPEND_INDEX: UINT = 0x7FFFFFFF;
+
+/// Where [`Element::set_html`] puts the HTML.
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum SetHtmlMode {
+    /// Replace the element's content.
+    ReplaceContent = SET_ELEMENT_HTML::SIH_REPLACE_CONTENT as i32,
+    /// Insert before the first child.
+    InsertAtStart = SET_ELEMENT_HTML::SIH_INSERT_AT_START as i32,
+    /// Append after the last child.
+    AppendAfterLast = SET_ELEMENT_HTML::SIH_APPEND_AFTER_LAST as i32,
+    /// Replace the element itself.
+    Replace = SET_ELEMENT_HTML::SOH_REPLACE as i32,
+    /// Insert before the element.
+    InsertBefore = SET_ELEMENT_HTML::SOH_INSERT_BEFORE as i32,
+    /// Insert after the element.
+    InsertAfter = SET_ELEMENT_HTML::SOH_INSERT_AFTER as i32,
+}
+
+impl From<SetHtmlMode> for SET_ELEMENT_HTML {
+    fn from(value: SetHtmlMode) -> Self {
+        match value {
+            SetHtmlMode::ReplaceContent => SET_ELEMENT_HTML::SIH_REPLACE_CONTENT,
+            SetHtmlMode::InsertAtStart => SET_ELEMENT_HTML::SIH_INSERT_AT_START,
+            SetHtmlMode::AppendAfterLast => SET_ELEMENT_HTML::SIH_APPEND_AFTER_LAST,
+            SetHtmlMode::Replace => SET_ELEMENT_HTML::SOH_REPLACE,
+            SetHtmlMode::InsertBefore => SET_ELEMENT_HTML::SOH_INSERT_BEFORE,
+            SetHtmlMode::InsertAfter => SET_ELEMENT_HTML::SOH_INSERT_AFTER,
+        }
+    }
+}
+
+impl Clone for Element {
+    fn clone(&self) -> Self {
+        if self.0.is_null() {
+            return Self(self.0);
+        }
+
+        if let Ok(api) = sapi() {
+            let _ = api.use_element(self.0);
+        }
+
+        Self(self.0)
+    }
+}
+
+impl Drop for Element {
+    fn drop(&mut self) {
+        if self.0.is_null() {
+            return;
+        }
+
+        if let Ok(api) = sapi() {
+            let _ = api.unuse_element(self.0);
+        }
+    }
+}
+
+impl Debug for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Element").field(&self.0).finish()
+    }
+}
+
+impl Eq for Element {}
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+/// Borrows a raw handle as an [`Element`] without touching its reference count.
+///
+/// # Safety
+/// The handle should be null or a valid element handle alive for the whole borrow,
+/// e.g. the one passed to an element event procedure.
+pub(crate) unsafe fn element_ref(he: &HELEMENT) -> &Element {
+    // SAFETY: Element has #[repr(transparent)]
+    unsafe { &*(he as *const HELEMENT as *const Element) }
+}
+
+/// Makes the raw params of the event sent to `target`, the returned name buffer
+/// backs the `name` pointer and should outlive the params.
+fn raw_event_params(
+    target: HELEMENT,
+    params: &EventParams,
+) -> (BEHAVIOR_EVENT_PARAMS, Option<Vec<u16>>) {
+    let name = match &params.event {
+        BehaviorEvent::Custom(name) => Some(utf::str_to_utf16(name)),
+        _ => None,
+    };
+    let raw = BEHAVIOR_EVENT_PARAMS {
+        cmd: params.event.code(),
+        heTarget: target,
+        he: params.source.as_ref().map_or(target, |source| source.0),
+        reason: params.reason as UINT_PTR,
+        data: params.data.0,
+        name: name.as_ref().map_or(std::ptr::null(), |name| name.as_ptr()),
+    };
+
+    (raw, name)
+}
+
+/// Converts the `range` of `count` children to the `[first, last)` indices,
+/// fails with [`DomError::InvalidParameter`] if the range is reversed or out of bounds.
+fn children_range(range: impl RangeBounds<usize>, count: usize) -> Result<(UINT, UINT)> {
+    let first = match range.start_bound() {
+        Bound::Included(&n) => Some(n),
+        Bound::Excluded(&n) => n.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let last = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1),
+        Bound::Excluded(&n) => Some(n),
+        Bound::Unbounded => Some(count),
+    };
+
+    match (first, last) {
+        (Some(first), Some(last)) if first <= last && last <= count => {
+            match (UINT::try_from(first), UINT::try_from(last)) {
+                (Ok(first), Ok(last)) => Ok((first, last)),
+                _ => Err(Error::DomError(DomError::InvalidParameter)),
+            }
+        }
+        _ => Err(Error::DomError(DomError::InvalidParameter)),
+    }
+}
+
+type Comparator<'a> = Box<dyn FnMut(&Element, &Element) -> Ordering + 'a>;
+
+struct SortState<'a> {
+    cmp: Comparator<'a>,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+unsafe extern "C" fn sort_thunk(he1: HELEMENT, he2: HELEMENT, param: LPVOID) -> INT {
+    let state = &mut *(param as *mut SortState);
+    if state.panic.is_some() {
+        // the comparator has panicked, let Sciter finish as fast as possible
+        return 0;
+    }
+
+    let (a, b) = (element_ref(&he1), element_ref(&he2));
+    match std::panic::catch_unwind(AssertUnwindSafe(|| (state.cmp)(a, b))) {
+        Ok(ordering) => ordering as INT,
+        Err(panic) => {
+            state.panic = Some(panic);
+            0
+        }
+    }
+}
+
+struct SelectState<'a> {
+    callback: Box<dyn FnMut(Element) -> bool + 'a>,
+}
+
+unsafe extern "C" fn select_thunk(he: HELEMENT, param: LPVOID) -> SBOOL {
+    let state = &mut *(param as *mut SelectState);
+    let Ok(Some(element)) = Element::wrap(he) else {
+        return 0;
+    };
+
+    if (state.callback)(element) {
+        // continue enumeration
+        0
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_html_mode() {
+        assert_eq!(
+            SET_ELEMENT_HTML::from(SetHtmlMode::ReplaceContent),
+            SET_ELEMENT_HTML::SIH_REPLACE_CONTENT
+        );
+        assert_eq!(
+            SET_ELEMENT_HTML::from(SetHtmlMode::AppendAfterLast),
+            SET_ELEMENT_HTML::SIH_APPEND_AFTER_LAST
+        );
+        assert_eq!(
+            SET_ELEMENT_HTML::from(SetHtmlMode::InsertAfter),
+            SET_ELEMENT_HTML::SOH_INSERT_AFTER
+        );
+    }
+
+    #[test]
+    fn test_null_clone() {
+        let he: HELEMENT = std::ptr::null_mut();
+        let element = unsafe { element_ref(&he) }.clone();
+        assert!(element.as_raw().is_null());
+    }
+
+    #[test]
+    fn test_raw_event_params() {
+        let target = 0x10 as HELEMENT;
+
+        let params = EventParams::new(BehaviorEvent::ButtonClick).with_reason(3);
+        let (raw, name) = raw_event_params(target, &params);
+        assert_eq!(raw.cmd, BEHAVIOR_EVENTS::BUTTON_CLICK as u32);
+        assert_eq!(raw.heTarget, target);
+        assert_eq!(raw.he, target);
+        assert_eq!(raw.reason, 3);
+        assert_eq!(raw.data.t, VALUE_TYPE::T_UNDEFINED.0 as UINT);
+        assert!(raw.name.is_null());
+        assert!(name.is_none());
+
+        let params = EventParams::new(BehaviorEvent::custom("device-connected"));
+        let (raw, _name) = raw_event_params(target, &params);
+        assert_eq!(raw.cmd, BEHAVIOR_EVENTS::CUSTOM as u32);
+        assert_eq!(
+            unsafe { utf::u16_ptr_to_string(raw.name) },
+            "device-connected"
+        );
+    }
+
+    #[test]
+    fn test_children_range() {
+        assert_eq!(children_range(.., 10).unwrap(), (0, 10));
+        assert_eq!(children_range(2..5, 10).unwrap(), (2, 5));
+        assert_eq!(children_range(2..=5, 10).unwrap(), (2, 6));
+        assert_eq!(children_range(3.., 10).unwrap(), (3, 10));
+        assert_eq!(children_range(..=9, 10).unwrap(), (0, 10));
+        assert_eq!(
+            children_range((Bound::Excluded(2), Bound::Unbounded), 10).unwrap(),
+            (3, 10)
+        );
+        assert_eq!(children_range(4..4, 10).unwrap(), (4, 4));
+        assert_eq!(children_range(.., 0).unwrap(), (0, 0));
+        assert!(children_range(.., UINT::MAX as usize + 1).is_err());
+    }
+
+    #[test]
+    #[should_panic = "called `Result::unwrap()` on an `Err` value: DomError(InvalidParameter)"]
+    fn test_children_range_reversed() {
+        #[allow(clippy::reversed_empty_ranges)]
+        children_range(5..2, 10).unwrap();
+    }
+
+    #[test]
+    #[should_panic = "called `Result::unwrap()` on an `Err` value: DomError(InvalidParameter)"]
+    fn test_children_range_out_of_bounds() {
+        children_range(0..1000, 10).unwrap();
+    }
+
+    #[test]
+    #[should_panic = "called `Result::unwrap()` on an `Err` value: DomError(InvalidParameter)"]
+    fn test_children_range_overflow() {
+        children_range(..=usize::MAX, usize::MAX).unwrap();
+    }
+
+    #[test]
+    fn test_attributes() {
+        let element = Element::create("div", Some("text")).unwrap();
+        element.set_attr("id", "test").unwrap();
+        element.set_attr("class", "a b").unwrap();
+
+        assert_eq!(element.attr("id").unwrap().as_deref(), Some("test"));
+        assert_eq!(element.attr("missing").unwrap(), None);
+
+        let attributes: Vec<_> = element.attributes().unwrap().collect();
+        assert_eq!(
+            attributes,
+            vec![
+                ("id".to_string(), "test".to_string()),
+                ("class".to_string(), "a b".to_string())
+            ]
+        );
+
+        element.remove_attr("id").unwrap();
+        assert_eq!(element.attributes().unwrap().count(), 1);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::bindings::ELEMENT_AREAS;
+
+/// Which box of the element [`crate::Element::location`] returns.
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub enum ElementArea {
+    /// The content box.
+    #[default]
+    Content = ELEMENT_AREAS::CONTENT_BOX as i32,
+    /// Content box plus paddings.
+    Padding = ELEMENT_AREAS::PADDING_BOX as i32,
+    /// Padding box plus borders.
+    Border = ELEMENT_AREAS::BORDER_BOX as i32,
+    /// Border box plus margins.
+    Margin = ELEMENT_AREAS::MARGIN_BOX as i32,
+    /// The area of the background image.
+    BackImage = ELEMENT_AREAS::BACK_IMAGE_AREA as i32,
+    /// The area of the foreground image.
+    ForeImage = ELEMENT_AREAS::FORE_IMAGE_AREA as i32,
+    /// The scrollable area of the element.
+    Scrollable = ELEMENT_AREAS::SCROLLABLE_AREA as i32,
+}
+
+/// The origin of the coordinates [`crate::Element::location`] returns.
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub enum RelativeTo {
+    /// Relative to the root element.
+    #[default]
+    Root = ELEMENT_AREAS::ROOT_RELATIVE as i32,
+    /// Relative to the element's own origin.
+    Element = ELEMENT_AREAS::SELF_RELATIVE as i32,
+    /// Relative to the parent (container) element.
+    Container = ELEMENT_AREAS::CONTAINER_RELATIVE as i32,
+    /// Relative to the window (view), use it to position native overlays.
+    View = ELEMENT_AREAS::VIEW_RELATIVE as i32,
+}
+
+pub(crate) fn area_bits(area: ElementArea, relative_to: RelativeTo) -> u32 {
+    area as u32 | relative_to as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_bits() {
+        assert_eq!(
+            area_bits(ElementArea::Border, RelativeTo::View),
+            ELEMENT_AREAS::BORDER_BOX as u32 | ELEMENT_AREAS::VIEW_RELATIVE as u32
+        );
+        assert_eq!(
+            area_bits(ElementArea::default(), RelativeTo::default()),
+            ELEMENT_AREAS::CONTENT_BOX as u32 | ELEMENT_AREAS::ROOT_RELATIVE as u32
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::{cell::Cell, rc::Rc};
+
+use crate::{api::sapi, bindings::*, dispatch_event, Element, EventHandler, Result};
+
+/// Keeps an [`EventHandler`] attached to an element, see [`Element::attach_handler`].
+///
+/// Dropping the guard detaches the handler. The handler is also released
+/// if the element gets destroyed while the guard is alive.
+#[must_use = "the handler is detached when the guard is dropped"]
+pub struct HandlerGuard {
+    element: Element,
+    state: *mut HandlerState,
+    attached: Rc<Cell<bool>>,
+}
+
+struct HandlerState {
+    handler: Box<dyn for<'s> EventHandler<'s>>,
+    attached: Rc<Cell<bool>>,
+    // nesting level of `handler_thunk` calls, the handler can detach itself while handling an event
+    dispatching: Cell<u32>,
+    released: Cell<bool>,
+}
+
+/// Marks the state as released and frees it unless an event is being dispatched to it,
+/// in that case the outermost `handler_thunk` call frees it.
+///
+/// # Safety
+/// `state` should be a live pointer created by [`HandlerGuard::attach`].
+unsafe fn release(state: *mut HandlerState) {
+    (*state).attached.set(false);
+    (*state).released.set(true);
+    if (*state).dispatching.get() == 0 {
+        drop(Box::from_raw(state));
+    }
+}
+
+impl HandlerGuard {
+    pub(super) fn attach(
+        element: &Element,
+        handler: impl for<'s> EventHandler<'s>,
+    ) -> Result<Self> {
+        let attached = Rc::new(Cell::new(true));
+        let state = Box::into_raw(Box::new(HandlerState {
+            handler: Box::new(handler),
+            attached: attached.clone(),
+            dispatching: Cell::new(0),
+            released: Cell::new(false),
+        }));
+
+        if let Err(err) = sapi().and_then(|api| {
+            api.attach_event_handler(element.as_raw(), Some(handler_thunk), state as LPVOID)
+        }) {
+            // SAFETY: Sciter has not taken the handler
+            drop(unsafe { Box::from_raw(state) });
+            return Err(err);
+        }
+
+        Ok(Self {
+            element: element.clone(),
+            state,
+            attached,
+        })
+    }
+
+    /// The element the handler is attached to.
+    pub fn element(&self) -> &Element {
+        &self.element
+    }
+
+    /// Returns `false` if Sciter has already detached the handler, e.g. the element was destroyed.
+    pub fn is_attached(&self) -> bool {
+        self.attached.get()
+    }
+
+    /// Detaches the handler, reporting the error if any.
+    pub fn detach(mut self) -> Result<()> {
+        self.detach_impl()
+    }
+
+    fn detach_impl(&mut self) -> Result<()> {
+        if !self.attached.get() {
+            return Ok(());
+        }
+
+        sapi()?.detach_event_handler(
+            self.element.as_raw(),
+            Some(handler_thunk),
+            self.state as LPVOID,
+        )?;
+
+        // Sciter notifies the handler with `BEHAVIOR_DETACH` which releases the state,
+        // release it here if that did not happen
+        if self.attached.get() {
+            unsafe { release(self.state) };
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for HandlerGuard {
+    fn drop(&mut self) {
+        let _ = self.detach_impl();
+    }
+}
+
+impl std::fmt::Debug for HandlerGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HandlerGuard")
+            .field("element", &self.element)
+            .field("attached", &self.attached.get())
+            .finish()
+    }
+}
+
+unsafe extern "C" fn handler_thunk(tag: LPVOID, he: HELEMENT, evtg: UINT, params: LPVOID) -> SBOOL {
+    if tag.is_null() {
+        return false as _;
+    }
+
+    let state = tag as *mut HandlerState;
+    let is_detach = evtg == EVENT_GROUPS::HANDLE_INITIALIZATION.0 as UINT
+        && (*(params as *const INITIALIZATION_PARAMS)).cmd
+            == INITIALIZATION_EVENTS::BEHAVIOR_DETACH as u32;
+
+    let dispatching = &(*state).dispatching;
+    dispatching.set(dispatching.get() + 1);
+    let res = dispatch_event((*state).handler.as_mut(), he, evtg, params);
+    dispatching.set(dispatching.get() - 1);
+
+    if is_detach || (*state).released.get() {
+        // frees the state if this is the outermost call
+        release(state);
+    }
+
+    res
+}
@@ -0,0 +1,48 @@
+use std::hash::{Hash, Hasher};
+
+use crate::{api::sapi, bindings::UINT, Element, Result, WindowHandle};
+
+/// A weak reference to an element that can be stored anywhere and sent across threads.
+///
+/// It does not keep the element alive, [`ElementId::upgrade`] resolves it
+/// back to the element on the UI thread.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ElementId {
+    window: WindowHandle,
+    uid: UINT,
+}
+
+// the window handle is used as an opaque identifier here,
+// the element itself is resolved on the UI thread only
+unsafe impl Send for ElementId {}
+unsafe impl Sync for ElementId {}
+
+impl ElementId {
+    pub(super) fn new(window: WindowHandle, uid: UINT) -> Self {
+        Self { window, uid }
+    }
+
+    /// Returns the element, or `None` if it has been removed from the DOM.
+    pub fn upgrade(&self) -> Result<Option<Element>> {
+        let he = sapi()?.get_element_by_uid(self.window.into(), self.uid)?;
+        Element::wrap(he)
+    }
+
+    /// The window the element belongs to.
+    pub fn window(&self) -> WindowHandle {
+        self.window
+    }
+
+    /// The element's unique identifier within its window.
+    pub fn uid(&self) -> u32 {
+        self.uid
+    }
+}
+
+// `Eq` compares the window too, hashing the uid alone is still consistent with it:
+// equal ids always have equal uids, and uids rarely collide across windows
+impl Hash for ElementId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uid.hash(state);
+    }
+}
@@ -0,0 +1,17 @@
+/// Where the popup is shown, see [`crate::Element::show_popup`].
+///
+/// The values follow the numeric keypad layout, e.g. `Bottom` is `2` and `TopRight` is `9`.
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub enum PopupPlacement {
+    BottomLeft = 1,
+    #[default]
+    Bottom = 2,
+    BottomRight = 3,
+    Left = 4,
+    Center = 5,
+    Right = 6,
+    TopLeft = 7,
+    Top = 8,
+    TopRight = 9,
+}
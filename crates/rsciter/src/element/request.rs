@@ -0,0 +1,102 @@
+use crate::{bindings::*, element::element_ref, utf, Element};
+
+/// HTTP method and mode of [`crate::Element::http_request`].
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub enum RequestType {
+    #[default]
+    GetAsync = REQUEST_TYPE::GET_ASYNC as i32,
+    PostAsync = REQUEST_TYPE::POST_ASYNC as i32,
+    GetSync = REQUEST_TYPE::GET_SYNC as i32,
+    PostSync = REQUEST_TYPE::POST_SYNC as i32,
+}
+
+impl From<RequestType> for REQUEST_TYPE {
+    fn from(value: RequestType) -> Self {
+        match value {
+            RequestType::GetAsync => REQUEST_TYPE::GET_ASYNC,
+            RequestType::PostAsync => REQUEST_TYPE::POST_ASYNC,
+            RequestType::GetSync => REQUEST_TYPE::GET_SYNC,
+            RequestType::PostSync => REQUEST_TYPE::POST_SYNC,
+        }
+    }
+}
+
+/// Data requested by [`crate::Element::request_data`] or [`crate::Element::http_request`],
+/// see [`crate::EventHandler::on_data`].
+#[repr(transparent)]
+pub struct DataArrived<'a>(&'a DATA_ARRIVED_PARAMS);
+
+impl<'a> DataArrived<'a> {
+    /// The element that initiated the request, if any.
+    pub fn initiator(&self) -> Option<&'a Element> {
+        if self.0.initiator.is_null() {
+            return None;
+        }
+
+        Some(unsafe { element_ref(&self.0.initiator) })
+    }
+
+    pub fn raw_uri(&self) -> LPCWSTR {
+        self.0.uri
+    }
+
+    pub fn uri(&self) -> String {
+        unsafe { utf::u16_ptr_to_string(self.0.uri) }
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        if self.0.data.is_null() {
+            return &[];
+        }
+
+        unsafe { std::slice::from_raw_parts(self.0.data, self.0.dataSize as usize) }
+    }
+
+    pub fn data_type(&self) -> SciterResourceType {
+        SciterResourceType::from(self.0.dataType as i32)
+    }
+
+    /// HTTP status code, `0` if the request failed without a response.
+    pub fn status(&self) -> u32 {
+        self.0.status
+    }
+}
+
+impl<'a> From<&'a DATA_ARRIVED_PARAMS> for DataArrived<'a> {
+    fn from(value: &'a DATA_ARRIVED_PARAMS) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_arrived() {
+        let params: DATA_ARRIVED_PARAMS = unsafe { std::mem::zeroed() };
+        let data = DataArrived::from(&params);
+        assert!(data.initiator().is_none());
+        assert!(data.data().is_empty());
+        assert_eq!(data.uri(), "");
+        assert_eq!(data.status(), 0);
+        assert_eq!(data.data_type(), SciterResourceType::RT_DATA_HTML);
+
+        let bytes = b"{\"ok\":true}";
+        let uri = utf::str_to_utf16("https://example.com/data.json");
+        let params = DATA_ARRIVED_PARAMS {
+            data: bytes.as_ptr(),
+            dataSize: bytes.len() as UINT,
+            dataType: SciterResourceType::RT_DATA_RAW as UINT,
+            status: 200,
+            uri: uri.as_ptr(),
+            ..unsafe { std::mem::zeroed() }
+        };
+        let data = DataArrived::from(&params);
+        assert_eq!(data.data(), bytes);
+        assert_eq!(data.uri(), "https://example.com/data.json");
+        assert_eq!(data.status(), 200);
+        assert_eq!(data.data_type(), SciterResourceType::RT_DATA_RAW);
+    }
+}
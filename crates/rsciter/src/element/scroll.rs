@@ -0,0 +1,28 @@
+use crate::{
+    bindings::{POINT, RECT, SCITER_SCROLL_FLAGS, SIZE},
+    flags::impl_flags_ops,
+};
+
+/// Scroll state of an element, see [`crate::Element::scroll_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollInfo {
+    /// Current scroll position.
+    pub pos: POINT,
+    /// The visible area of the element.
+    pub view_rect: RECT,
+    /// The size of the whole scrollable content.
+    pub content_size: SIZE,
+}
+
+/// Options of [`crate::Element::scroll_into_view`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct ScrollFlags(pub(crate) i32);
+
+impl ScrollFlags {
+    /// Scroll the element to the top of the view, otherwise scroll the minimal distance.
+    pub const TO_TOP: Self = Self(SCITER_SCROLL_FLAGS::SCROLL_TO_TOP.0);
+    /// Animate the scrolling.
+    pub const SMOOTH: Self = Self(SCITER_SCROLL_FLAGS::SCROLL_SMOOTH.0);
+}
+
+impl_flags_ops!(ScrollFlags: i32);
@@ -0,0 +1,95 @@
+use crate::{bindings::ELEMENT_STATE_BITS, flags::impl_flags_ops};
+
+/// Runtime state of an element, the flags correspond to CSS pseudo-classes like `:hover` or `:checked`.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub struct ElementState(pub(crate) u32);
+
+impl ElementState {
+    /// `:link`
+    pub const LINK: Self = Self(ELEMENT_STATE_BITS::STATE_LINK as u32);
+    /// `:hover`
+    pub const HOVER: Self = Self(ELEMENT_STATE_BITS::STATE_HOVER as u32);
+    /// `:active`
+    pub const ACTIVE: Self = Self(ELEMENT_STATE_BITS::STATE_ACTIVE as u32);
+    /// `:focus`
+    pub const FOCUS: Self = Self(ELEMENT_STATE_BITS::STATE_FOCUS as u32);
+    /// `:visited`
+    pub const VISITED: Self = Self(ELEMENT_STATE_BITS::STATE_VISITED as u32);
+    /// `:current`, current (hot) item
+    pub const CURRENT: Self = Self(ELEMENT_STATE_BITS::STATE_CURRENT as u32);
+    /// `:checked`, element is checked (or selected)
+    pub const CHECKED: Self = Self(ELEMENT_STATE_BITS::STATE_CHECKED as u32);
+    /// `:disabled`, element is disabled
+    pub const DISABLED: Self = Self(ELEMENT_STATE_BITS::STATE_DISABLED as u32);
+    /// `:read-only`, readonly input element
+    pub const READONLY: Self = Self(ELEMENT_STATE_BITS::STATE_READONLY as u32);
+    /// `:expanded`, expanded state - nodes in tvs
+    pub const EXPANDED: Self = Self(ELEMENT_STATE_BITS::STATE_EXPANDED as u32);
+    /// `:collapsed`, collapsed state - nodes in tvs
+    pub const COLLAPSED: Self = Self(ELEMENT_STATE_BITS::STATE_COLLAPSED as u32);
+    /// `:incomplete`, one of fore/back images requested but not delivered
+    pub const INCOMPLETE: Self = Self(ELEMENT_STATE_BITS::STATE_INCOMPLETE as u32);
+    /// `:animating`, is animating currently
+    pub const ANIMATING: Self = Self(ELEMENT_STATE_BITS::STATE_ANIMATING as u32);
+    /// `:focusable`, will accept focus
+    pub const FOCUSABLE: Self = Self(ELEMENT_STATE_BITS::STATE_FOCUSABLE as u32);
+    /// `:anchor`, anchor in selection (used with current in selects)
+    pub const ANCHOR: Self = Self(ELEMENT_STATE_BITS::STATE_ANCHOR as u32);
+    /// `:synthetic`, this is a synthetic element - don't save it
+    pub const SYNTHETIC: Self = Self(ELEMENT_STATE_BITS::STATE_SYNTHETIC as u32);
+    /// `:owns-popup`, this element has a popup open
+    pub const OWNS_POPUP: Self = Self(ELEMENT_STATE_BITS::STATE_OWNS_POPUP as u32);
+    /// `:tab-focus`, focus gained by tab traversal
+    pub const TABFOCUS: Self = Self(ELEMENT_STATE_BITS::STATE_TABFOCUS as u32);
+    /// `:empty`, empty - element is empty (text.size() == 0 && subs.size() == 0)
+    pub const EMPTY: Self = Self(ELEMENT_STATE_BITS::STATE_EMPTY as u32);
+    /// `:busy`, busy, loading
+    pub const BUSY: Self = Self(ELEMENT_STATE_BITS::STATE_BUSY as u32);
+    /// `:drag-over`, drag over the block that can accept it (so is current drop target)
+    pub const DRAG_OVER: Self = Self(ELEMENT_STATE_BITS::STATE_DRAG_OVER as u32);
+    /// `:drop-target`, active drop target
+    pub const DROP_TARGET: Self = Self(ELEMENT_STATE_BITS::STATE_DROP_TARGET as u32);
+    /// `:moving`, dragging/moving - the flag is set for the moving block
+    pub const MOVING: Self = Self(ELEMENT_STATE_BITS::STATE_MOVING as u32);
+    /// `:copying`, dragging/copying - the flag is set for the copying block
+    pub const COPYING: Self = Self(ELEMENT_STATE_BITS::STATE_COPYING as u32);
+    /// `:drag-source`, element that is a drag source
+    pub const DRAG_SOURCE: Self = Self(ELEMENT_STATE_BITS::STATE_DRAG_SOURCE as u32);
+    /// `:drop-marker`, element is a drop marker
+    pub const DROP_MARKER: Self = Self(ELEMENT_STATE_BITS::STATE_DROP_MARKER as u32);
+    /// `:pressed`, pressed - close to active but has wider life span
+    pub const PRESSED: Self = Self(ELEMENT_STATE_BITS::STATE_PRESSED as u32);
+    /// `:popup`, this element is out of flow - popup
+    pub const POPUP: Self = Self(ELEMENT_STATE_BITS::STATE_POPUP as u32);
+    /// `:ltr`, the element or one of its containers has `dir=ltr` declared
+    pub const IS_LTR: Self = Self(ELEMENT_STATE_BITS::STATE_IS_LTR as u32);
+    /// `:rtl`, the element or one of its containers has `dir=rtl` declared
+    pub const IS_RTL: Self = Self(ELEMENT_STATE_BITS::STATE_IS_RTL as u32);
+}
+
+impl_flags_ops!(ElementState);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let mut state = ElementState::HOVER | ElementState::FOCUS;
+
+        assert!(state.contains(ElementState::HOVER));
+        assert!(state.contains(ElementState::HOVER | ElementState::FOCUS));
+        assert!(!state.contains(ElementState::HOVER | ElementState::ACTIVE));
+        assert_eq!(state & ElementState::FOCUS, ElementState::FOCUS);
+        assert!((state & ElementState::ACTIVE).is_empty());
+
+        state.remove(ElementState::HOVER);
+        assert_eq!(state, ElementState::FOCUS);
+        assert_eq!(state.bits(), ELEMENT_STATE_BITS::STATE_FOCUS as u32);
+
+        state |= ElementState::CHECKED;
+        state &= ElementState::CHECKED;
+        assert_eq!(state, ElementState::CHECKED);
+        assert!(ElementState::empty().is_empty());
+    }
+}
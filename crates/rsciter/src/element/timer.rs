@@ -0,0 +1,130 @@
+use std::{cell::RefCell, collections::HashSet, time::Duration};
+
+use crate::{
+    api::sapi, bindings::*, DomError, Element, Error, EventGroups, EventHandler, HandlerGuard,
+    Result,
+};
+
+thread_local! {
+    // (element, id) pairs of the live guards, Sciter keeps a single timer per pair
+    static RUNNING: RefCell<HashSet<(usize, usize)>> = RefCell::new(HashSet::new());
+}
+
+/// Reserves the `id` of the element's timer, fails if a live guard already uses it.
+fn register(he: HELEMENT, id: usize) -> Result<()> {
+    if RUNNING.with(|running| running.borrow_mut().insert((he as usize, id))) {
+        Ok(())
+    } else {
+        Err(Error::DomError(DomError::InvalidParameter))
+    }
+}
+
+fn unregister(he: HELEMENT, id: usize) {
+    RUNNING.with(|running| running.borrow_mut().remove(&(he as usize, id)));
+}
+
+/// Keeps the timer started by [`Element::set_timer`] running, dropping the guard stops it.
+#[must_use = "the timer is stopped when the guard is dropped"]
+#[derive(Debug)]
+pub struct TimerGuard {
+    handler: HandlerGuard,
+    id: usize,
+}
+
+impl TimerGuard {
+    pub(super) fn start(
+        element: &Element,
+        interval: Duration,
+        id: usize,
+        callback: impl FnMut() -> bool + 'static,
+    ) -> Result<Self> {
+        register(element.as_raw(), id)?;
+
+        let start = || {
+            let handler = element.attach_handler(TimerHandler {
+                id,
+                callback: Box::new(callback),
+            })?;
+
+            let milliseconds = interval.as_millis().clamp(1, UINT::MAX as u128) as UINT;
+            sapi()?.set_timer(element.as_raw(), milliseconds, id as UINT_PTR)?;
+            Ok(handler)
+        };
+
+        match start() {
+            Ok(handler) => Ok(Self { handler, id }),
+            Err(err) => {
+                unregister(element.as_raw(), id);
+                Err(err)
+            }
+        }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Stops the timer, reporting the error if any.
+    pub fn stop(self) -> Result<()> {
+        self.stop_impl()
+    }
+
+    fn stop_impl(&self) -> Result<()> {
+        unregister(self.handler.element().as_raw(), self.id);
+
+        if !self.handler.is_attached() {
+            return Ok(());
+        }
+
+        sapi()?.set_timer(self.handler.element().as_raw(), 0, self.id as UINT_PTR)
+    }
+}
+
+impl Drop for TimerGuard {
+    fn drop(&mut self) {
+        let _ = self.stop_impl();
+    }
+}
+
+struct TimerHandler {
+    id: usize,
+    callback: Box<dyn FnMut() -> bool>,
+}
+
+impl<'s> EventHandler<'s> for TimerHandler {
+    fn subscription(&'s mut self, element: &Element) -> Option<EventGroups> {
+        let _ = element;
+        Some(EventGroups::HANDLE_TIMER)
+    }
+
+    fn on_timer(&'s mut self, element: &Element, params: &TIMER_PARAMS) -> Result<bool> {
+        let _ = element;
+        if params.timerId != self.id as UINT_PTR {
+            return Ok(false);
+        }
+
+        // Sciter stops the timer if nobody handled the tick
+        Ok((self.callback)())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let (first, second) = (0x10 as HELEMENT, 0x20 as HELEMENT);
+
+        register(first, 1).unwrap();
+        assert!(matches!(
+            register(first, 1),
+            Err(Error::DomError(DomError::InvalidParameter))
+        ));
+        register(first, 2).unwrap();
+        register(second, 1).unwrap();
+
+        unregister(first, 1);
+        register(first, 1).unwrap();
+    }
+}
@@ -45,6 +45,9 @@ pub enum Error {
     #[error("Value error: {0}")]
     ValueError(#[from] ValueError),
 
+    #[error("DOM error: {0}")]
+    DomError(#[from] DomError),
+
     #[error("Script error: {0}")]
     ScriptError(String),
 
@@ -114,3 +117,73 @@ impl From<crate::bindings::VALUE_RESULT> for ValueError {
         }
     }
 }
+
+#[derive(thiserror::Error, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DomError {
+    #[error("Invalid window handle")]
+    InvalidHwnd,
+
+    #[error("Invalid element handle")]
+    InvalidHandle,
+
+    #[error("Attempt to use element handle which is not attached to the document")]
+    PassiveHandle,
+
+    #[error("Invalid parameter")]
+    InvalidParameter,
+
+    #[error("Operation failed")]
+    OperationFailed,
+
+    #[error("Not handled")]
+    NotHandled,
+
+    #[error("Unknown: {0}")]
+    Unknown(i32),
+}
+
+impl From<i32> for DomError {
+    fn from(value: i32) -> Self {
+        match value {
+            x if x == crate::bindings::SCDOM_INVALID_HWND as i32 => DomError::InvalidHwnd,
+            x if x == crate::bindings::SCDOM_INVALID_HANDLE as i32 => DomError::InvalidHandle,
+            x if x == crate::bindings::SCDOM_PASSIVE_HANDLE as i32 => DomError::PassiveHandle,
+            x if x == crate::bindings::SCDOM_INVALID_PARAMETER as i32 => DomError::InvalidParameter,
+            x if x == crate::bindings::SCDOM_OPERATION_FAILED as i32 => DomError::OperationFailed,
+            crate::bindings::SCDOM_OK_NOT_HANDLED => DomError::NotHandled,
+            unknown => DomError::Unknown(unknown),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::*;
+
+    #[test]
+    fn test_dom_error_from() {
+        assert_eq!(
+            DomError::from(SCDOM_INVALID_HWND as i32),
+            DomError::InvalidHwnd
+        );
+        assert_eq!(
+            DomError::from(SCDOM_INVALID_HANDLE as i32),
+            DomError::InvalidHandle
+        );
+        assert_eq!(
+            DomError::from(SCDOM_PASSIVE_HANDLE as i32),
+            DomError::PassiveHandle
+        );
+        assert_eq!(
+            DomError::from(SCDOM_INVALID_PARAMETER as i32),
+            DomError::InvalidParameter
+        );
+        assert_eq!(
+            DomError::from(SCDOM_OPERATION_FAILED as i32),
+            DomError::OperationFailed
+        );
+        assert_eq!(DomError::from(SCDOM_OK_NOT_HANDLED), DomError::NotHandled);
+        assert_eq!(DomError::from(42), DomError::Unknown(42));
+    }
+}
@@ -0,0 +1,632 @@
+use crate::{bindings::*, element::element_ref, flags::impl_flags_ops, utf, Element, Value};
+
+macro_rules! behavior_events {
+    ($( $(#[$meta:meta])* $name:ident = $code:ident, )*) => {
+        /// A behavior (DOM) event, see [`crate::Element::fire_event`].
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum BehaviorEvent {
+            $( $(#[$meta])* $name, )*
+            /// A custom event with the given name, e.g. to be handled by `addEventListener` in script.
+            Custom(String),
+            /// An application specific event code, starting from
+            /// `BEHAVIOR_EVENTS::FIRST_APPLICATION_EVENT_CODE`.
+            Application(u32),
+        }
+
+        impl BehaviorEvent {
+            /// Returns the `BEHAVIOR_EVENTS` code of the event.
+            pub fn code(&self) -> u32 {
+                match self {
+                    $( Self::$name => BEHAVIOR_EVENTS::$code as u32, )*
+                    Self::Custom(_) => BEHAVIOR_EVENTS::CUSTOM as u32,
+                    Self::Application(code) => *code,
+                }
+            }
+
+            /// Makes the event from its code, `name` is used for `BEHAVIOR_EVENTS::CUSTOM` only.
+            pub fn from_code(code: u32, name: Option<&str>) -> Self {
+                match code {
+                    $( x if x == BEHAVIOR_EVENTS::$code as u32 => Self::$name, )*
+                    x if x == BEHAVIOR_EVENTS::CUSTOM as u32 => {
+                        Self::Custom(name.unwrap_or_default().to_string())
+                    }
+                    code => Self::Application(code),
+                }
+            }
+        }
+    };
+}
+
+behavior_events! {
+    /// Click on a button
+    ButtonClick = BUTTON_CLICK,
+    /// Mouse down or key down in a button
+    ButtonPress = BUTTON_PRESS,
+    /// Value of an input element has been changed
+    ValueChanged = VALUE_CHANGED,
+    /// Before the value change
+    ValueChanging = VALUE_CHANGING,
+    /// Selection in a `<select>` has been changed
+    SelectionChanged = SELECTION_CHANGED,
+    /// Before the selection change
+    SelectionChanging = SELECTION_CHANGING,
+    /// Request to show a popup
+    PopupRequest = POPUP_REQUEST,
+    /// Popup element has been measured and ready to be shown
+    PopupReady = POPUP_READY,
+    /// Popup element is closed
+    PopupDismissed = POPUP_DISMISSED,
+    /// Menu item activated by mouse hover or by keyboard
+    MenuItemActive = MENU_ITEM_ACTIVE,
+    /// Menu item click
+    MenuItemClick = MENU_ITEM_CLICK,
+    /// Context menu request for the element
+    ContextMenuRequest = CONTEXT_MENU_REQUEST,
+    /// Broadcast notification, sent to all elements of some container being shown or hidden
+    VisualStatusChanged = VISUAL_STATUS_CHANGED,
+    /// Broadcast notification, sent to all elements of some container that got new value of `:disabled` state
+    DisabledStatusChanged = DISABLED_STATUS_CHANGED,
+    /// Popup is about to be closed
+    PopupDismissing = POPUP_DISMISSING,
+    /// Content has been changed, is posted to the element that gets content changed
+    ContentChanged = CONTENT_CHANGED,
+    /// Hyperlink click
+    HyperlinkClick = HYPERLINK_CLICK,
+    /// Element was collapsed
+    ElementCollapsed = ELEMENT_COLLAPSED,
+    /// Element was expanded
+    ElementExpanded = ELEMENT_EXPANDED,
+    /// Activate (select) child, used for example by accesskeys behaviors
+    ActivateChild = ACTIVATE_CHILD,
+    /// Form submit request
+    FormSubmit = FORM_SUBMIT,
+    /// Form reset request
+    FormReset = FORM_RESET,
+    /// Document in `<frame>` or root document is complete
+    DocumentComplete = DOCUMENT_COMPLETE,
+    /// Request to the history to push a new entry
+    HistoryPush = HISTORY_PUSH,
+    /// Request to the history to drop the current entry
+    HistoryDrop = HISTORY_DROP,
+    /// Request to the history to go back
+    HistoryPrior = HISTORY_PRIOR,
+    /// Request to the history to go forward
+    HistoryNext = HISTORY_NEXT,
+    /// History state has been changed
+    HistoryStateChanged = HISTORY_STATE_CHANGED,
+    /// Close the popup request
+    ClosePopup = CLOSE_POPUP,
+    /// Request to show the tooltip
+    RequestTooltip = REQUEST_TOOLTIP,
+    /// Animation started or ended
+    Animation = ANIMATION,
+    /// Transition started or ended
+    Transition = TRANSITION,
+    /// Swipe gesture
+    Swipe = SWIPE,
+    /// Document has been created, the script namespace is ready
+    DocumentCreated = DOCUMENT_CREATED,
+    /// Request to close the document
+    DocumentCloseRequest = DOCUMENT_CLOSE_REQUEST,
+    /// Document is about to be closed
+    DocumentClose = DOCUMENT_CLOSE,
+    /// Document has been loaded and all scripts have been executed
+    DocumentReady = DOCUMENT_READY,
+    /// Document has been parsed
+    DocumentParsed = DOCUMENT_PARSED,
+    /// Document is closing
+    DocumentClosing = DOCUMENT_CLOSING,
+    /// Request to close the container (window)
+    ContainerCloseRequest = CONTAINER_CLOSE_REQUEST,
+    /// The container (window) is closing
+    ContainerClosing = CONTAINER_CLOSING,
+    /// `<video>` has been initialized
+    VideoInitialized = VIDEO_INITIALIZED,
+    /// `<video>` playback started
+    VideoStarted = VIDEO_STARTED,
+    /// `<video>` playback stopped
+    VideoStopped = VIDEO_STOPPED,
+    /// `<video>` binding request
+    VideoBindRq = VIDEO_BIND_RQ,
+    /// `<video>` frame request
+    VideoFrameRequest = VIDEO_FRAME_REQUEST,
+    /// Pagination started
+    PaginationStarts = PAGINATION_STARTS,
+    /// Pagination page is ready
+    PaginationPage = PAGINATION_PAGE,
+    /// Pagination finished
+    PaginationEnds = PAGINATION_ENDS,
+}
+
+impl BehaviorEvent {
+    /// Shortcut for [`BehaviorEvent::Custom`].
+    pub fn custom(name: impl Into<String>) -> Self {
+        Self::Custom(name.into())
+    }
+}
+
+/// Parameters of an event fired by [`crate::Element::fire_event`].
+#[derive(Debug)]
+pub struct EventParams {
+    pub(crate) event: BehaviorEvent,
+    pub(crate) source: Option<Element>,
+    pub(crate) reason: usize,
+    pub(crate) data: Value,
+}
+
+impl EventParams {
+    pub fn new(event: BehaviorEvent) -> Self {
+        Self {
+            event,
+            source: None,
+            reason: 0,
+            data: Value::new(),
+        }
+    }
+
+    /// Sets the element that generated the event, the target element is used if omitted.
+    pub fn with_source(mut self, source: &Element) -> Self {
+        self.source = Some(source.clone());
+        self
+    }
+
+    /// Sets the event specific reason, e.g. `CLICK_REASON`.
+    pub fn with_reason(mut self, reason: usize) -> Self {
+        self.reason = reason;
+        self
+    }
+
+    /// Attaches the payload, available as `event.data` in script.
+    pub fn with_data(mut self, data: Value) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn event(&self) -> &BehaviorEvent {
+        &self.event
+    }
+}
+
+impl From<BehaviorEvent> for EventParams {
+    fn from(event: BehaviorEvent) -> Self {
+        Self::new(event)
+    }
+}
+
+macro_rules! event_codes {
+    (
+        $(#[$enum_meta:meta])*
+        $enum:ident: $raw:ident {
+            $( $(#[$meta:meta])* $name:ident = $code:ident, )*
+        }
+    ) => {
+        $(#[$enum_meta])*
+        #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+        pub enum $enum {
+            $( $(#[$meta])* $name, )*
+            /// A code not known to this crate.
+            Unknown(u32),
+        }
+
+        impl $enum {
+            /// Returns the raw event code.
+            pub fn code(&self) -> u32 {
+                match self {
+                    $( Self::$name => $raw::$code as u32, )*
+                    Self::Unknown(code) => *code,
+                }
+            }
+
+            /// Makes the event from its raw code, phase bits must be cleared.
+            pub fn from_code(code: u32) -> Self {
+                match code {
+                    $( x if x == $raw::$code as u32 => Self::$name, )*
+                    code => Self::Unknown(code),
+                }
+            }
+        }
+    };
+}
+
+event_codes! {
+    /// Kind of a mouse event.
+    MouseAction: MOUSE_EVENTS {
+        Enter = MOUSE_ENTER,
+        Leave = MOUSE_LEAVE,
+        Move = MOUSE_MOVE,
+        Up = MOUSE_UP,
+        Down = MOUSE_DOWN,
+        DoubleClick = MOUSE_DCLICK,
+        Wheel = MOUSE_WHEEL,
+        /// Mouse pressed ticks
+        Tick = MOUSE_TICK,
+        /// Mouse stays idle for some time
+        Idle = MOUSE_IDLE,
+        /// Item dropped, target is the drop target
+        Drop = DROP,
+        /// Drag arrived to the target element
+        DragEnter = DRAG_ENTER,
+        /// Drag left the target element
+        DragLeave = DRAG_LEAVE,
+        /// Drag source notification about the drag start
+        DragRequest = DRAG_REQUEST,
+        TripleClick = MOUSE_TCLICK,
+        /// Mouse drag request, e.g. from `:drag-source` elements
+        MouseDragRequest = MOUSE_DRAG_REQUEST,
+        Click = MOUSE_CLICK,
+        /// Hit test request
+        HitTest = MOUSE_HIT_TEST,
+    }
+}
+
+event_codes! {
+    /// Kind of a keyboard event.
+    KeyAction: KEY_EVENTS {
+        Down = KEY_DOWN,
+        Up = KEY_UP,
+        /// A character was typed, the key code is the character code
+        Char = KEY_CHAR,
+    }
+}
+
+event_codes! {
+    /// Kind of a focus event.
+    FocusAction: FOCUS_EVENTS {
+        /// The container lost the focus from any element inside it
+        Out = FOCUS_OUT,
+        /// The container got the focus on an element inside it
+        In = FOCUS_IN,
+        /// The target element got the focus
+        Got = FOCUS_GOT,
+        /// The target element lost the focus
+        Lost = FOCUS_LOST,
+        /// Bubbling event, request to set the focus on the element
+        Request = FOCUS_REQUEST,
+        /// Bubbling event, request to advance the focus
+        AdvanceRequest = FOCUS_ADVANCE_REQUEST,
+    }
+}
+
+/// Pressed mouse buttons.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub struct MouseButtons(pub(crate) u32);
+
+impl MouseButtons {
+    /// Usually the left button
+    pub const MAIN: Self = Self(MOUSE_BUTTONS::MAIN_MOUSE_BUTTON as u32);
+    /// Usually the right button
+    pub const SECONDARY: Self = Self(MOUSE_BUTTONS::PROP_MOUSE_BUTTON as u32);
+    pub const MIDDLE: Self = Self(MOUSE_BUTTONS::MIDDLE_MOUSE_BUTTON as u32);
+}
+
+impl_flags_ops!(MouseButtons);
+
+/// Pressed keyboard modifiers.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub struct Modifiers(pub(crate) u32);
+
+impl Modifiers {
+    pub const CONTROL: Self = Self(KEYBOARD_STATES::CONTROL_KEY_PRESSED as u32);
+    pub const SHIFT: Self = Self(KEYBOARD_STATES::SHIFT_KEY_PRESSED as u32);
+    pub const ALT: Self = Self(KEYBOARD_STATES::ALT_KEY_PRESSED as u32);
+    pub const RIGHT_SHIFT: Self = Self(KEYBOARD_STATES::RIGHT_SHIFT_KEY_PRESSED as u32);
+    /// The Command key on macOS
+    pub const CMD: Self = Self(KEYBOARD_STATES::CMD_KEY_PRESSED as u32);
+}
+
+impl_flags_ops!(Modifiers);
+
+/// Propagation phase of an event.
+///
+/// Sciter first sends an event from the root down to the target (sinking),
+/// then up from the target to the root (bubbling).
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum EventPhase {
+    Sinking,
+    Bubbling,
+    /// A bubbling event that was already processed by some handler.
+    Handled,
+}
+
+const PHASE_BITS: u32 = PHASE_MASK::SINKING as u32 | PHASE_MASK::HANDLED as u32;
+
+impl EventPhase {
+    /// Decodes the phase bits of the raw `cmd`.
+    pub fn from_cmd(cmd: u32) -> Self {
+        if cmd & PHASE_MASK::HANDLED as u32 != 0 {
+            Self::Handled
+        } else if cmd & PHASE_MASK::SINKING as u32 != 0 {
+            Self::Sinking
+        } else {
+            Self::Bubbling
+        }
+    }
+}
+
+/// Returns the raw `cmd` without the phase bits.
+fn event_code(cmd: u32) -> u32 {
+    cmd & !PHASE_BITS
+}
+
+/// Returns the raw mouse `cmd` without the phase and the `DRAGGING` bits.
+fn mouse_code(cmd: u32) -> u32 {
+    event_code(cmd) & !(MOUSE_EVENTS::DRAGGING as u32)
+}
+
+fn nullable_element_ref(he: &HELEMENT) -> Option<&Element> {
+    if he.is_null() {
+        return None;
+    }
+
+    Some(unsafe { element_ref(he) })
+}
+
+/// A mouse event, see [`crate::EventHandler::on_mouse`].
+#[repr(transparent)]
+pub struct MouseEvent<'a>(&'a MOUSE_PARAMS);
+
+impl<'a> MouseEvent<'a> {
+    pub fn kind(&self) -> MouseAction {
+        MouseAction::from_code(mouse_code(self.0.cmd))
+    }
+
+    pub fn phase(&self) -> EventPhase {
+        EventPhase::from_cmd(self.0.cmd)
+    }
+
+    /// `true` if the event is sent while a dragging operation is in progress.
+    pub fn is_dragging(&self) -> bool {
+        self.0.cmd & MOUSE_EVENTS::DRAGGING as u32 != 0
+    }
+
+    /// The element under the cursor.
+    pub fn target(&self) -> Option<&'a Element> {
+        nullable_element_ref(&self.0.target)
+    }
+
+    /// The cursor position relative to the element.
+    pub fn pos(&self) -> POINT {
+        self.0.pos
+    }
+
+    /// The cursor position relative to the window (view).
+    pub fn pos_view(&self) -> POINT {
+        self.0.pos_view
+    }
+
+    pub fn buttons(&self) -> MouseButtons {
+        MouseButtons(self.0.button_state)
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers(self.0.alt_state)
+    }
+
+    /// `true` if the cursor is over the element's `foreground-image`.
+    pub fn is_on_icon(&self) -> bool {
+        self.0.is_on_icon != 0
+    }
+
+    /// The element being dragged, for drag and drop events.
+    pub fn dragging(&self) -> Option<&'a Element> {
+        nullable_element_ref(&self.0.dragging)
+    }
+
+    pub fn raw(&self) -> &'a MOUSE_PARAMS {
+        self.0
+    }
+}
+
+impl<'a> From<&'a MOUSE_PARAMS> for MouseEvent<'a> {
+    fn from(value: &'a MOUSE_PARAMS) -> Self {
+        Self(value)
+    }
+}
+
+/// A keyboard event, see [`crate::EventHandler::on_key`].
+#[repr(transparent)]
+pub struct KeyEvent<'a>(&'a KEY_PARAMS);
+
+impl<'a> KeyEvent<'a> {
+    pub fn kind(&self) -> KeyAction {
+        KeyAction::from_code(event_code(self.0.cmd))
+    }
+
+    pub fn phase(&self) -> EventPhase {
+        EventPhase::from_cmd(self.0.cmd)
+    }
+
+    /// The element having the focus.
+    pub fn target(&self) -> Option<&'a Element> {
+        nullable_element_ref(&self.0.target)
+    }
+
+    /// The virtual key code, or the character code for [`KeyAction::Char`].
+    pub fn key_code(&self) -> u32 {
+        self.0.key_code
+    }
+
+    /// The typed character for [`KeyAction::Char`] events.
+    pub fn char(&self) -> Option<char> {
+        match self.kind() {
+            KeyAction::Char => char::from_u32(self.0.key_code),
+            _ => None,
+        }
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers(self.0.alt_state)
+    }
+
+    pub fn raw(&self) -> &'a KEY_PARAMS {
+        self.0
+    }
+}
+
+impl<'a> From<&'a KEY_PARAMS> for KeyEvent<'a> {
+    fn from(value: &'a KEY_PARAMS) -> Self {
+        Self(value)
+    }
+}
+
+/// A focus event, see [`crate::EventHandler::on_focus`].
+#[repr(transparent)]
+pub struct FocusEvent<'a>(&'a FOCUS_PARAMS);
+
+impl<'a> FocusEvent<'a> {
+    pub fn kind(&self) -> FocusAction {
+        FocusAction::from_code(event_code(self.0.cmd))
+    }
+
+    pub fn phase(&self) -> EventPhase {
+        EventPhase::from_cmd(self.0.cmd)
+    }
+
+    /// The element that gets or loses the focus.
+    pub fn target(&self) -> Option<&'a Element> {
+        nullable_element_ref(&self.0.target)
+    }
+
+    /// The raw cause of the focus change, e.g. `FOCUS_CMD_TYPE` for [`FocusAction::AdvanceRequest`].
+    pub fn cause(&self) -> u32 {
+        self.0.cause
+    }
+
+    pub fn raw(&self) -> &'a FOCUS_PARAMS {
+        self.0
+    }
+}
+
+impl<'a> From<&'a FOCUS_PARAMS> for FocusEvent<'a> {
+    fn from(value: &'a FOCUS_PARAMS) -> Self {
+        Self(value)
+    }
+}
+
+/// A behavior (DOM) event, see [`crate::EventHandler::on_event`].
+#[repr(transparent)]
+pub struct DomEvent<'a>(&'a BEHAVIOR_EVENT_PARAMS);
+
+impl<'a> DomEvent<'a> {
+    pub fn kind(&self) -> BehaviorEvent {
+        let code = event_code(self.0.cmd);
+        if code == BEHAVIOR_EVENTS::CUSTOM as u32 {
+            return BehaviorEvent::Custom(self.name().unwrap_or_default());
+        }
+
+        BehaviorEvent::from_code(code, None)
+    }
+
+    pub fn phase(&self) -> EventPhase {
+        EventPhase::from_cmd(self.0.cmd)
+    }
+
+    /// The element the event is targeted to.
+    pub fn target(&self) -> Option<&'a Element> {
+        nullable_element_ref(&self.0.heTarget)
+    }
+
+    /// The element that generated the event.
+    pub fn source(&self) -> Option<&'a Element> {
+        nullable_element_ref(&self.0.he)
+    }
+
+    /// The event specific reason, e.g. `CLICK_REASON` or `EDIT_CHANGED_REASON`.
+    pub fn reason(&self) -> usize {
+        self.0.reason
+    }
+
+    /// The event payload, `event.data` in script.
+    pub fn data(&self) -> &'a Value {
+        // SAFETY: Value has #[repr(transparent)]
+        unsafe { &*(&self.0.data as *const VALUE as *const Value) }
+    }
+
+    /// The event name, set for custom events.
+    pub fn name(&self) -> Option<String> {
+        if self.0.name.is_null() {
+            return None;
+        }
+
+        Some(unsafe { utf::u16_ptr_to_string(self.0.name) })
+    }
+
+    pub fn raw(&self) -> &'a BEHAVIOR_EVENT_PARAMS {
+        self.0
+    }
+}
+
+impl<'a> From<&'a BEHAVIOR_EVENT_PARAMS> for DomEvent<'a> {
+    fn from(value: &'a BEHAVIOR_EVENT_PARAMS) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase() {
+        let click = MOUSE_EVENTS::MOUSE_CLICK as u32;
+        let sinking = click | PHASE_MASK::SINKING as u32;
+        let handled = click | PHASE_MASK::HANDLED as u32;
+
+        assert_eq!(EventPhase::from_cmd(click), EventPhase::Bubbling);
+        assert_eq!(EventPhase::from_cmd(sinking), EventPhase::Sinking);
+        assert_eq!(EventPhase::from_cmd(handled), EventPhase::Handled);
+
+        assert_eq!(
+            MouseAction::from_code(event_code(sinking)),
+            MouseAction::Click
+        );
+        assert_eq!(
+            MouseAction::from_code(event_code(handled)),
+            MouseAction::Click
+        );
+    }
+
+    #[test]
+    fn test_dragging() {
+        let drag_move = MOUSE_EVENTS::MOUSE_MOVE as u32 | MOUSE_EVENTS::DRAGGING as u32;
+        let sinking = drag_move | PHASE_MASK::SINKING as u32;
+
+        assert_eq!(
+            MouseAction::from_code(mouse_code(drag_move)),
+            MouseAction::Move
+        );
+        assert_eq!(
+            MouseAction::from_code(mouse_code(sinking)),
+            MouseAction::Move
+        );
+        assert_eq!(EventPhase::from_cmd(sinking), EventPhase::Sinking);
+
+        let params = MOUSE_PARAMS {
+            cmd: sinking,
+            ..unsafe { std::mem::zeroed() }
+        };
+        let event = MouseEvent::from(&params);
+        assert!(event.is_dragging());
+        assert_eq!(event.kind(), MouseAction::Move);
+    }
+
+    #[test]
+    fn test_codes() {
+        assert_eq!(
+            KeyAction::from_code(KeyAction::Char.code()),
+            KeyAction::Char
+        );
+        assert_eq!(FocusAction::from_code(1234), FocusAction::Unknown(1234));
+
+        let custom = BehaviorEvent::custom("device-connected");
+        assert_eq!(custom.code(), BEHAVIOR_EVENTS::CUSTOM as u32);
+        assert_eq!(
+            BehaviorEvent::from_code(custom.code(), Some("device-connected")),
+            custom
+        );
+        assert_eq!(
+            BehaviorEvent::from_code(BEHAVIOR_EVENTS::BUTTON_CLICK as u32, None),
+            BehaviorEvent::ButtonClick
+        );
+    }
+}
@@ -0,0 +1,64 @@
+/// Implements the common bit flag operations for a `Flags(bits)` newtype.
+macro_rules! impl_flags_ops {
+    ($type:ty) => {
+        impl_flags_ops!($type: u32);
+    };
+    ($type:ty: $bits:ty) => {
+        impl $type {
+            /// No flags set.
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            pub const fn bits(&self) -> $bits {
+                self.0
+            }
+
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// Returns `true` if all the `flags` are set.
+            pub const fn contains(&self, flags: Self) -> bool {
+                self.0 & flags.0 == flags.0
+            }
+
+            pub fn remove(&mut self, flags: Self) -> &mut Self {
+                self.0 &= !flags.0;
+                self
+            }
+        }
+
+        impl ::std::ops::BitOr<$type> for $type {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+        }
+
+        impl ::std::ops::BitOrAssign for $type {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl ::std::ops::BitAnd<$type> for $type {
+            type Output = Self;
+            #[inline]
+            fn bitand(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+        }
+
+        impl ::std::ops::BitAndAssign for $type {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+    };
+}
+
+pub(crate) use impl_flags_ops;
@@ -4,8 +4,12 @@ pub mod api;
 pub mod app;
 mod archive;
 mod debug;
+mod element;
 mod error;
+mod event;
 mod event_handler;
+mod flags;
+mod node;
 mod options;
 pub mod som;
 pub mod utf;
@@ -14,8 +18,11 @@ mod window;
 
 pub use archive::*;
 pub use debug::*;
+pub use element::*;
 pub use error::*;
+pub use event::*;
 pub use event_handler::*;
+pub use node::*;
 pub use options::*;
 pub use value::*;
 pub use window::*;
@@ -0,0 +1,277 @@
+use std::fmt::Debug;
+
+use crate::{api::sapi, bindings::*, utf, DomError, Element, Error, Result};
+
+/// A reference counted handle to a DOM node: element, text or comment.
+#[repr(transparent)]
+pub struct Node(HNODE);
+
+impl Node {
+    /// Wraps the raw handle, incrementing its reference count.
+    pub fn from_raw(hn: HNODE) -> Result<Self> {
+        Self::wrap(hn)?.ok_or(Error::DomError(DomError::InvalidHandle))
+    }
+
+    /// Same as [`Node::from_raw`], but returns `None` for the null handle.
+    pub(crate) fn wrap(hn: HNODE) -> Result<Option<Self>> {
+        if hn.is_null() {
+            return Ok(None);
+        }
+
+        sapi()?.node_add_ref(hn)?;
+        Ok(Some(Self(hn)))
+    }
+
+    /// Takes the ownership of the already referenced handle, e.g. returned by `SciterCreateTextNode`.
+    fn wrap_owned(hn: HNODE) -> Result<Self> {
+        if hn.is_null() {
+            return Err(Error::DomError(DomError::InvalidHandle));
+        }
+
+        Ok(Self(hn))
+    }
+
+    /// Creates a new detached text node.
+    pub fn text_node(text: &str) -> Result<Self> {
+        let hn = sapi()?.create_text_node(text)?;
+        Self::wrap_owned(hn)
+    }
+
+    /// Creates a new detached comment node.
+    pub fn comment_node(text: &str) -> Result<Self> {
+        let hn = sapi()?.create_comment_node(text)?;
+        Self::wrap_owned(hn)
+    }
+
+    /// Returns the underlying handle, the reference count is not affected.
+    pub fn as_raw(&self) -> HNODE {
+        self.0
+    }
+
+    pub fn kind(&self) -> Result<NodeKind> {
+        sapi()?
+            .node_type(self.0)
+            .map(|kind| NodeKind::from(kind as i32))
+    }
+
+    pub fn is_element(&self) -> Result<bool> {
+        self.kind().map(|kind| kind == NodeKind::Element)
+    }
+
+    pub fn is_text(&self) -> Result<bool> {
+        self.kind().map(|kind| kind == NodeKind::Text)
+    }
+
+    pub fn is_comment(&self) -> Result<bool> {
+        self.kind().map(|kind| kind == NodeKind::Comment)
+    }
+
+    /// Returns the element if this node is an element node, `None` otherwise.
+    pub fn to_element(&self) -> Result<Option<Element>> {
+        if !self.is_element()? {
+            return Ok(None);
+        }
+
+        let he = sapi()?.node_cast_to_element(self.0)?;
+        Element::wrap(he)
+    }
+
+    /// Returns the parent element, `None` for detached nodes.
+    pub fn parent(&self) -> Result<Option<Element>> {
+        let he = sapi()?.node_parent(self.0)?;
+        Element::wrap(he)
+    }
+
+    pub fn first_child(&self) -> Result<Option<Node>> {
+        let hn = sapi()?.node_first_child(self.0)?;
+        Self::wrap(hn)
+    }
+
+    pub fn last_child(&self) -> Result<Option<Node>> {
+        let hn = sapi()?.node_last_child(self.0)?;
+        Self::wrap(hn)
+    }
+
+    pub fn next_sibling(&self) -> Result<Option<Node>> {
+        let hn = sapi()?.node_next_sibling(self.0)?;
+        Self::wrap(hn)
+    }
+
+    pub fn prev_sibling(&self) -> Result<Option<Node>> {
+        let hn = sapi()?.node_prev_sibling(self.0)?;
+        Self::wrap(hn)
+    }
+
+    pub fn children_count(&self) -> Result<usize> {
+        sapi()?.node_children_count(self.0).map(|n| n as usize)
+    }
+
+    /// Returns the nth child node, `None` if there is no such child.
+    pub fn child(&self, n: usize) -> Result<Option<Node>> {
+        let hn = sapi()?.node_nth_child(self.0, n as UINT)?;
+        Self::wrap(hn)
+    }
+
+    /// Returns all child nodes, including text and comment ones.
+    pub fn children(&self) -> Result<impl Iterator<Item = Node>> {
+        let mut children = Vec::new();
+        if let Some(first) = self.first_child()? {
+            children.push(first.clone());
+            children.extend(first.next_siblings()?);
+        }
+
+        Ok(children.into_iter())
+    }
+
+    /// Returns all nodes following this one.
+    pub fn next_siblings(&self) -> Result<impl Iterator<Item = Node>> {
+        let mut siblings = Vec::new();
+        let mut current = self.next_sibling()?;
+        while let Some(node) = current {
+            current = node.next_sibling()?;
+            siblings.push(node);
+        }
+
+        Ok(siblings.into_iter())
+    }
+
+    /// Returns the node's text, for element nodes it is the text content.
+    pub fn text(&self) -> Result<String> {
+        let mut text = String::new();
+        sapi()?.node_get_text(self.0, Some(utf::wstr_thunk), &mut text as *mut _ as _)?;
+        Ok(text)
+    }
+
+    pub fn set_text(&self, text: &str) -> Result<()> {
+        sapi()?.node_set_text(self.0, text)
+    }
+
+    /// Inserts the `node` relative to this one.
+    pub fn insert(&self, node: &Node, position: NodeInsertPosition) -> Result<()> {
+        sapi()?.node_insert(self.0, position.into(), node.0)
+    }
+
+    /// Takes the node out of the DOM tree, it can be inserted again later.
+    pub fn detach(&self) -> Result<()> {
+        sapi()?.node_remove(self.0, false)
+    }
+
+    /// Takes the node out of the DOM tree and destroys it.
+    pub fn remove(self) -> Result<()> {
+        sapi()?.node_remove(self.0, true)
+    }
+}
+
+impl Element {
+    /// Returns the element as a [`Node`].
+    pub fn to_node(&self) -> Result<Node> {
+        let hn = sapi()?.node_cast_from_element(self.as_raw())?;
+        Node::from_raw(hn)
+    }
+}
+
+impl Clone for Node {
+    fn clone(&self) -> Self {
+        if let Ok(api) = sapi() {
+            let _ = api.node_add_ref(self.0);
+        }
+
+        Self(self.0)
+    }
+}
+
+impl Drop for Node {
+    fn drop(&mut self) {
+        if let Ok(api) = sapi() {
+            let _ = api.node_release(self.0);
+        }
+    }
+}
+
+impl Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Node").field(&self.0).finish()
+    }
+}
+
+impl Eq for Node {}
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NodeKind {
+    Element,
+    Text,
+    Comment,
+    Unknown(i32),
+}
+
+impl From<i32> for NodeKind {
+    fn from(value: i32) -> Self {
+        match value {
+            x if x == NODE_TYPE::NT_ELEMENT as i32 => Self::Element,
+            x if x == NODE_TYPE::NT_TEXT as i32 => Self::Text,
+            x if x == NODE_TYPE::NT_COMMENT as i32 => Self::Comment,
+            v => Self::Unknown(v),
+        }
+    }
+}
+
+/// Where [`Node::insert`] puts the node.
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NodeInsertPosition {
+    /// Insert before this node.
+    Before = NODE_INS_TARGET::NIT_BEFORE as i32,
+    /// Insert after this node.
+    After = NODE_INS_TARGET::NIT_AFTER as i32,
+    /// Append as the last child of this node.
+    Append = NODE_INS_TARGET::NIT_APPEND as i32,
+    /// Insert as the first child of this node.
+    Prepend = NODE_INS_TARGET::NIT_PREPEND as i32,
+}
+
+impl From<NodeInsertPosition> for NODE_INS_TARGET {
+    fn from(value: NodeInsertPosition) -> Self {
+        match value {
+            NodeInsertPosition::Before => NODE_INS_TARGET::NIT_BEFORE,
+            NodeInsertPosition::After => NODE_INS_TARGET::NIT_AFTER,
+            NodeInsertPosition::Append => NODE_INS_TARGET::NIT_APPEND,
+            NodeInsertPosition::Prepend => NODE_INS_TARGET::NIT_PREPEND,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_position() {
+        assert_eq!(
+            NODE_INS_TARGET::from(NodeInsertPosition::Before),
+            NODE_INS_TARGET::NIT_BEFORE
+        );
+        assert_eq!(
+            NODE_INS_TARGET::from(NodeInsertPosition::After),
+            NODE_INS_TARGET::NIT_AFTER
+        );
+        assert_eq!(
+            NODE_INS_TARGET::from(NodeInsertPosition::Append),
+            NODE_INS_TARGET::NIT_APPEND
+        );
+        assert_eq!(
+            NODE_INS_TARGET::from(NodeInsertPosition::Prepend),
+            NODE_INS_TARGET::NIT_PREPEND
+        );
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(NodeKind::from(NODE_TYPE::NT_TEXT as i32), NodeKind::Text);
+        assert_eq!(NodeKind::from(42), NodeKind::Unknown(42));
+    }
+}
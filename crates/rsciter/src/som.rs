@@ -3,11 +3,10 @@ use std::{
     num::NonZero,
     ops::{Deref, DerefMut},
     os::raw::{c_char, c_long, c_void},
-    slice, str,
     sync::atomic::Ordering,
 };
 
-use crate::{api::sapi, bindings::*, Result, Value};
+use crate::{api::sapi, bindings::*, utf::str_thunk, Result, Value};
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -38,13 +37,6 @@ impl From<Atom> for som_atom_t {
     }
 }
 
-unsafe extern "C" fn str_thunk(data: LPCSTR, len: UINT, target_ptr: LPVOID) {
-    let data = slice::from_raw_parts(data as _, len as _);
-    let data = str::from_utf8_unchecked(data);
-    let target = target_ptr as *mut String;
-    *target = data.to_string();
-}
-
 #[repr(transparent)]
 struct RawAssetObj(som_asset_t);
 
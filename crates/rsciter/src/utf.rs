@@ -1,5 +1,7 @@
 use std::path::Path;
 
+use crate::bindings::{LPCBYTE, LPCSTR, LPCWSTR, LPVOID, UINT};
+
 /// # Safety
 /// ptr should be a zero-terminated string
 pub unsafe fn u16_ptr_to_slice<'a>(ptr: *const u16) -> &'a [u16] {
@@ -22,6 +24,11 @@ pub fn str_to_utf16(s: &str) -> Vec<u16> {
     s.encode_utf16().chain(std::iter::once(0)).collect()
 }
 
+/// Zero-terminated UTF-8 bytes, suitable for `LPCSTR` parameters
+pub fn str_to_utf8(s: &str) -> Vec<u8> {
+    s.bytes().chain(std::iter::once(0)).collect()
+}
+
 pub fn str_to_utf16_no_trailing_zero(s: &str) -> Vec<u16> {
     s.encode_utf16().collect()
 }
@@ -31,3 +38,25 @@ pub fn path_to_utf16(path: &Path) -> Vec<u16> {
     let s = path.to_string_lossy();
     str_to_utf16(&s)
 }
+
+/// `LPCSTR_RECEIVER` writing to `*mut String`
+pub(crate) unsafe extern "C" fn str_thunk(data: LPCSTR, len: UINT, target_ptr: LPVOID) {
+    let data = std::slice::from_raw_parts(data as _, len as _);
+    let data = std::str::from_utf8_unchecked(data);
+    let target = target_ptr as *mut String;
+    *target = data.to_string();
+}
+
+/// `LPCWSTR_RECEIVER` writing to `*mut String`
+pub(crate) unsafe extern "C" fn wstr_thunk(data: LPCWSTR, len: UINT, target_ptr: LPVOID) {
+    let data = std::slice::from_raw_parts(data, len as _);
+    let target = target_ptr as *mut String;
+    *target = String::from_utf16_lossy(data);
+}
+
+/// `LPCBYTE_RECEIVER` writing UTF-8 data to `*mut String`
+pub(crate) unsafe extern "C" fn utf8_thunk(data: LPCBYTE, len: UINT, target_ptr: LPVOID) {
+    let data = std::slice::from_raw_parts(data, len as _);
+    let target = target_ptr as *mut String;
+    *target = String::from_utf8_lossy(data).into_owned();
+}
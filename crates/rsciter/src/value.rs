@@ -4,7 +4,7 @@ use crate::{
     api::sapi,
     bindings::*,
     som::{Asset, HasPassport},
-    utf, Error, Result, ValueError,
+    utf, Element, Error, Node, Result, ValueError,
 };
 
 #[repr(transparent)]
@@ -133,6 +133,22 @@ impl Value {
         Self::map(&[])
     }
 
+    pub fn element(element: &Element) -> Result<Self> {
+        let mut this = Self::new();
+        if !sapi()?.element_wrap(&mut this.0, element.as_raw())? {
+            return Err(Error::ApiMethodFailed("SciterElementWrap"));
+        }
+        Ok(this)
+    }
+
+    pub fn node(node: &Node) -> Result<Self> {
+        let mut this = Self::new();
+        if !sapi()?.node_wrap(&mut this.0, node.as_raw())? {
+            return Err(Error::ApiMethodFailed("SciterNodeWrap"));
+        }
+        Ok(this)
+    }
+
     pub fn secure_string(str: impl AsRef<str>) -> Result<Self> {
         let mut this = Self::new();
         let data = utf::str_to_utf16_no_trailing_zero(str.as_ref());
@@ -377,6 +393,20 @@ impl Value {
         todo!()
     }
 
+    pub fn get_element(&self) -> Result<Element> {
+        match sapi()?.element_unwrap(&self.0)? {
+            Some(he) => Element::from_raw(he),
+            None => Err(Error::from(ValueError::IncompatibleType)),
+        }
+    }
+
+    pub fn get_node(&self) -> Result<Node> {
+        match sapi()?.node_unwrap(&self.0)? {
+            Some(hn) => Node::from_raw(hn),
+            None => Err(Error::from(ValueError::IncompatibleType)),
+        }
+    }
+
     pub fn get_asset(&self) {
         todo!()
     }
@@ -915,6 +945,21 @@ pub mod tests {
         assert_eq!(val, Value::string("SSS").unwrap());
     }
 
+    #[test]
+    fn test_element() {
+        let element = Element::create("div", None).unwrap();
+        let val = Value::element(&element).unwrap();
+
+        assert_eq!(val.get_element().unwrap(), element);
+    }
+
+    #[test]
+    #[should_panic = "called `Result::unwrap()` on an `Err` value: ValueError(IncompatibleType)"]
+    fn test_element_incompatible() {
+        let val = Value::int(1).unwrap();
+        val.get_element().unwrap();
+    }
+
     #[test]
     fn test_from_int_array() {
         let val = Value::try_from([1, 2, 3, 4]).unwrap();
@@ -1,7 +1,7 @@
 use super::Value;
 use crate::{
     som::{Asset, HasPassport},
-    Error, Result,
+    Element, Error, Node, Result,
 };
 
 pub trait FromValue<'a>: Sized {
@@ -133,6 +133,44 @@ impl ToValue for &String {
     }
 }
 
+impl_from!(Element, get_element);
+impl_from!(Node, get_node);
+
+macro_rules! impl_dom {
+    ($type:ty, $to:ident) => {
+        impl TryFrom<&$type> for Value {
+            type Error = Error;
+
+            fn try_from(val: &$type) -> Result<Self> {
+                Value::$to(val)
+            }
+        }
+
+        impl TryFrom<$type> for Value {
+            type Error = Error;
+
+            fn try_from(val: $type) -> Result<Self> {
+                Value::$to(&val)
+            }
+        }
+
+        impl ToValue for $type {
+            fn to_value(val: Self) -> Result<Value> {
+                Value::try_from(val)
+            }
+        }
+
+        impl ToValue for &$type {
+            fn to_value(val: Self) -> Result<Value> {
+                Value::try_from(val)
+            }
+        }
+    };
+}
+
+impl_dom!(Element, element);
+impl_dom!(Node, node);
+
 impl FromValue<'_> for Value {
     fn from_value(value: &Value) -> Result<Self> {
         value.make_copy()
@@ -7,12 +7,14 @@ mod delegate;
 mod flags;
 mod handle;
 mod host;
+mod input;
 
 pub use builder::*;
 pub use delegate::*;
 pub use flags::*;
 pub use handle::*;
 pub use host::*;
+pub use input::*;
 
 pub struct Window {
     handle: WindowHandle,
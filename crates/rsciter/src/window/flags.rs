@@ -1,4 +1,4 @@
-use crate::bindings::SCITER_CREATE_WINDOW_FLAGS;
+use crate::{bindings::SCITER_CREATE_WINDOW_FLAGS, flags::impl_flags_ops};
 
 pub struct WindowFlags(pub(crate) i32);
 
@@ -27,39 +27,4 @@ impl WindowFlags {
     pub const OWNS_VM: Self = Self(SCITER_CREATE_WINDOW_FLAGS::SW_OWNS_VM.0);
 }
 
-impl ::std::ops::BitOr<WindowFlags> for WindowFlags {
-    type Output = Self;
-    #[inline]
-    fn bitor(self, other: Self) -> Self {
-        Self(self.0 | other.0)
-    }
-}
-
-impl ::std::ops::BitOrAssign for WindowFlags {
-    #[inline]
-    fn bitor_assign(&mut self, rhs: WindowFlags) {
-        self.0 |= rhs.0;
-    }
-}
-
-impl ::std::ops::BitAnd<WindowFlags> for WindowFlags {
-    type Output = Self;
-    #[inline]
-    fn bitand(self, other: Self) -> Self {
-        Self(self.0 & other.0)
-    }
-}
-
-impl ::std::ops::BitAndAssign for WindowFlags {
-    #[inline]
-    fn bitand_assign(&mut self, rhs: Self) {
-        self.0 &= rhs.0;
-    }
-}
-
-impl WindowFlags {
-    pub fn remove(&mut self, flags: WindowFlags) -> &mut Self {
-        self.0 &= !flags.0;
-        self
-    }
-}
+impl_flags_ops!(WindowFlags: i32);
@@ -1,4 +1,7 @@
-use crate::{api::sapi, bindings::*, Error, Result, Value};
+use crate::{
+    api::sapi, bindings::*, Element, ElementArea, Error, FocusInput, KeyInput, MouseInput,
+    RelativeTo, Result, Value,
+};
 
 /// A handle to a Sciter window object.
 #[repr(transparent)]
@@ -74,6 +77,109 @@ impl WindowHandle {
         sapi()?.load_html(self.hwnd, html, base_url)
     }
 
+    /// Returns the root element of the loaded document, `None` if there is no document.
+    pub fn root(&self) -> Result<Option<Element>> {
+        Element::root(*self)
+    }
+
+    /// Returns all elements of the document matching the CSS selector(s).
+    pub fn select(&self, css: &str) -> Result<impl Iterator<Item = Element>> {
+        let elements: Vec<Element> = match self.root()? {
+            Some(root) => root.select(css)?.collect(),
+            None => Vec::new(),
+        };
+
+        Ok(elements.into_iter())
+    }
+
+    /// Returns the first element of the document matching the CSS selector(s).
+    pub fn select_first(&self, css: &str) -> Result<Option<Element>> {
+        match self.root()? {
+            Some(root) => root.select_first(css),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the element having the input focus, if any.
+    pub fn focused_element(&self) -> Result<Option<Element>> {
+        let he = sapi()?.get_focus_element(self.hwnd)?;
+        Element::wrap(he)
+    }
+
+    /// Returns the element highlighted by the inspector, if any.
+    pub fn highlighted_element(&self) -> Result<Option<Element>> {
+        let he = sapi()?.get_highlighted_element(self.hwnd)?;
+        Element::wrap(he)
+    }
+
+    /// Highlights the element like the inspector does, `None` removes the highlighting.
+    pub fn set_highlighted(&self, element: Option<&Element>) -> Result<()> {
+        let he = element.map_or(std::ptr::null_mut(), |element| element.as_raw());
+        sapi()?.set_highlighted_element(self.hwnd, he)
+    }
+
+    /// Sends the synthetic mouse event, returns `true` if it was processed.
+    pub fn inject_mouse(&self, input: MouseInput) -> Result<bool> {
+        let target = match input.target {
+            Some(target) => Some(target),
+            None => self.root()?,
+        };
+        let Some(target) = target else {
+            return Ok(false);
+        };
+
+        let origin = target.location(ElementArea::Border, RelativeTo::View)?;
+        let mut params = MOUSE_PARAMS {
+            cmd: input.action.code(),
+            target: target.as_raw(),
+            pos: POINT {
+                x: input.pos.x - origin.left,
+                y: input.pos.y - origin.top,
+            },
+            pos_view: input.pos,
+            button_state: input.buttons.bits(),
+            alt_state: input.modifiers.bits(),
+            cursor_type: 0,
+            is_on_icon: 0,
+            dragging: std::ptr::null_mut(),
+            dragging_mode: 0,
+        };
+        sapi()?.traverse_ui_event(EVENT_GROUPS::HANDLE_MOUSE, &mut params as *mut _ as LPVOID)
+    }
+
+    /// Sends the synthetic keyboard event, returns `true` if it was processed.
+    pub fn inject_key(&self, input: KeyInput) -> Result<bool> {
+        let target = match input.target {
+            Some(target) => Some(target),
+            None => match self.focused_element()? {
+                Some(focused) => Some(focused),
+                None => self.root()?,
+            },
+        };
+        let Some(target) = target else {
+            return Ok(false);
+        };
+
+        let mut params = KEY_PARAMS {
+            cmd: input.action.code(),
+            target: target.as_raw(),
+            key_code: input.key_code,
+            alt_state: input.modifiers.bits(),
+        };
+        sapi()?.traverse_ui_event(EVENT_GROUPS::HANDLE_KEY, &mut params as *mut _ as LPVOID)
+    }
+
+    /// Sends the synthetic focus event, returns `true` if it was processed.
+    pub fn inject_focus(&self, input: FocusInput) -> Result<bool> {
+        let mut params = FOCUS_PARAMS {
+            cmd: input.action.code(),
+            target: input.target.as_raw(),
+            cause: input.cause,
+            cancel: 0,
+        };
+        sapi()?.traverse_ui_event(EVENT_GROUPS::HANDLE_FOCUS, &mut params as *mut _ as LPVOID)
+    }
+
     /// Posts host notifiacation. The host will get it in [`HostNotifications::on_posted_notification`]
     pub fn notify_host(
         &self,
@@ -96,7 +202,7 @@ impl WindowHandle {
             .and_then(Self::check_error_string)
     }
 
-    fn check_error_string(value: Value) -> Result<Value> {
+    pub(crate) fn check_error_string(value: Value) -> Result<Value> {
         if value.is_error_string() {
             let err = value.to_string().unwrap_or_else(|_| "unknown".to_string());
             return Err(Error::ScriptError(err));
@@ -52,139 +52,139 @@ impl DefaultEventHandler {
 }
 
 impl<'s> EventHandler<'s> for DefaultEventHandler {
-    fn attached(&'s mut self, he: crate::bindings::HELEMENT) {
+    fn attached(&'s mut self, element: &crate::Element) {
         if let Some(handler) = self.custom_handler.as_mut() {
-            handler.attached(he);
+            handler.attached(element);
         }
     }
 
-    fn detached(&'s mut self, he: crate::bindings::HELEMENT) {
+    fn detached(&'s mut self, element: &crate::Element) {
         if let Some(handler) = self.custom_handler.as_mut() {
-            handler.detached(he);
+            handler.detached(element);
         }
     }
 
-    fn subscription(&'s mut self, he: crate::bindings::HELEMENT) -> Option<crate::EventGroups> {
+    fn subscription(&'s mut self, element: &crate::Element) -> Option<crate::EventGroups> {
         self.custom_handler
             .as_mut()
-            .and_then(move |it| it.subscription(he))
+            .and_then(move |it| it.subscription(element))
             .or(Some(crate::EventGroups::HANDLE_ALL))
     }
 
     fn on_mouse(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
-        mouse: &crate::bindings::MOUSE_PARAMS,
+        element: &crate::Element,
+        mouse: &crate::MouseEvent,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_mouse(he, mouse))
+            .map(move |it| it.on_mouse(element, mouse))
             .unwrap_or(Ok(false))
     }
 
     fn on_key(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
-        key: &crate::bindings::KEY_PARAMS,
+        element: &crate::Element,
+        key: &crate::KeyEvent,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_key(he, key))
+            .map(move |it| it.on_key(element, key))
             .unwrap_or(Ok(false))
     }
 
     fn on_focus(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
-        params: &crate::bindings::FOCUS_PARAMS,
+        element: &crate::Element,
+        event: &crate::FocusEvent,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_focus(he, params))
+            .map(move |it| it.on_focus(element, event))
             .unwrap_or(Ok(false))
     }
 
     fn on_draw(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
+        element: &crate::Element,
         params: &crate::bindings::DRAW_PARAMS,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_draw(he, params))
+            .map(move |it| it.on_draw(element, params))
             .unwrap_or(Ok(false))
     }
 
     fn on_timer(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
+        element: &crate::Element,
         params: &crate::bindings::TIMER_PARAMS,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_timer(he, params))
+            .map(move |it| it.on_timer(element, params))
             .unwrap_or(Ok(false))
     }
 
     fn on_event(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
-        params: &crate::bindings::BEHAVIOR_EVENT_PARAMS,
+        element: &crate::Element,
+        event: &crate::DomEvent,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_event(he, params))
+            .map(move |it| it.on_event(element, event))
             .unwrap_or(Ok(false))
     }
 
     fn on_method_call(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
+        element: &crate::Element,
         params: &crate::bindings::METHOD_PARAMS,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_method_call(he, params))
+            .map(move |it| it.on_method_call(element, params))
             .unwrap_or(Ok(false))
     }
 
     fn on_data(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
-        params: &crate::bindings::DATA_ARRIVED_PARAMS,
+        element: &crate::Element,
+        data: &crate::DataArrived,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_data(he, params))
+            .map(move |it| it.on_data(element, data))
             .unwrap_or(Ok(false))
     }
 
     fn on_scroll(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
+        element: &crate::Element,
         params: &crate::bindings::SCROLL_PARAMS,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_scroll(he, params))
+            .map(move |it| it.on_scroll(element, params))
             .unwrap_or(Ok(false))
     }
 
-    fn on_size(&'s mut self, he: crate::bindings::HELEMENT) -> crate::Result<bool> {
+    fn on_size(&'s mut self, element: &crate::Element) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_size(he))
+            .map(move |it| it.on_size(element))
             .unwrap_or(Ok(false))
     }
 
     fn on_scripting_method_call(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
+        element: &crate::Element,
         name: &str,
         args: &[crate::Value],
     ) -> crate::Result<Option<crate::Value>> {
         if let Some(custom) = self.custom_handler.as_mut() {
-            let res = custom.on_scripting_method_call(he, name, args);
+            let res = custom.on_scripting_method_call(element, name, args);
             if res.is_ok() {
                 return res;
             }
@@ -195,42 +195,42 @@ impl<'s> EventHandler<'s> for DefaultEventHandler {
 
     fn on_gesture(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
+        element: &crate::Element,
         params: &crate::bindings::GESTURE_PARAMS,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_gesture(he, params))
+            .map(move |it| it.on_gesture(element, params))
             .unwrap_or(Ok(false))
     }
 
     fn on_exchange(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
+        element: &crate::Element,
         params: &crate::bindings::EXCHANGE_PARAMS,
     ) -> crate::Result<bool> {
         self.custom_handler
             .as_mut()
-            .map(move |it| it.on_exchange(he, params))
+            .map(move |it| it.on_exchange(element, params))
             .unwrap_or(Ok(false))
     }
 
     fn on_attribute_change(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
+        element: &crate::Element,
         params: &crate::bindings::ATTRIBUTE_CHANGE_PARAMS,
     ) {
         if let Some(custom) = self.custom_handler.as_mut() {
-            custom.on_attribute_change(he, params);
+            custom.on_attribute_change(element, params);
         }
     }
 
     fn on_passport(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
+        element: &crate::Element,
     ) -> Result<Option<&'s crate::bindings::som_passport_t>> {
         if let Some(custom) = self.custom_handler.as_mut() {
-            return custom.on_passport(he);
+            return custom.on_passport(element);
         }
 
         Ok(None)
@@ -238,10 +238,10 @@ impl<'s> EventHandler<'s> for DefaultEventHandler {
 
     fn on_asset(
         &'s mut self,
-        he: crate::bindings::HELEMENT,
+        element: &crate::Element,
     ) -> Result<Option<&'s crate::bindings::som_asset_t>> {
         if let Some(custom) = self.custom_handler.as_mut() {
-            return custom.on_asset(he);
+            return custom.on_asset(element);
         }
 
         Ok(None)
@@ -0,0 +1,100 @@
+use crate::{
+    bindings::POINT, Element, FocusAction, KeyAction, Modifiers, MouseAction, MouseButtons,
+};
+
+/// Synthetic mouse event, see [`crate::WindowHandle::inject_mouse`].
+pub struct MouseInput {
+    pub(crate) action: MouseAction,
+    pub(crate) pos: POINT,
+    pub(crate) buttons: MouseButtons,
+    pub(crate) modifiers: Modifiers,
+    pub(crate) target: Option<Element>,
+}
+
+impl MouseInput {
+    /// Makes the event at `pos`, relative to the window (view) origin.
+    pub fn new(action: MouseAction, pos: POINT) -> Self {
+        Self {
+            action,
+            pos,
+            buttons: MouseButtons::empty(),
+            modifiers: Modifiers::empty(),
+            target: None,
+        }
+    }
+
+    pub fn with_buttons(mut self, buttons: MouseButtons) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Sets the target element, the root element is used if omitted.
+    pub fn with_target(mut self, target: &Element) -> Self {
+        self.target = Some(target.clone());
+        self
+    }
+}
+
+/// Synthetic keyboard event, see [`crate::WindowHandle::inject_key`].
+pub struct KeyInput {
+    pub(crate) action: KeyAction,
+    pub(crate) key_code: u32,
+    pub(crate) modifiers: Modifiers,
+    pub(crate) target: Option<Element>,
+}
+
+impl KeyInput {
+    /// Makes the event with the virtual key code, or the character code for [`KeyAction::Char`].
+    pub fn new(action: KeyAction, key_code: u32) -> Self {
+        Self {
+            action,
+            key_code,
+            modifiers: Modifiers::empty(),
+            target: None,
+        }
+    }
+
+    /// Shortcut for typing the character `ch`.
+    pub fn char(ch: char) -> Self {
+        Self::new(KeyAction::Char, ch as u32)
+    }
+
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Sets the target element, the focused element (or the root one) is used if omitted.
+    pub fn with_target(mut self, target: &Element) -> Self {
+        self.target = Some(target.clone());
+        self
+    }
+}
+
+/// Synthetic focus event, see [`crate::WindowHandle::inject_focus`].
+pub struct FocusInput {
+    pub(crate) action: FocusAction,
+    pub(crate) target: Element,
+    pub(crate) cause: u32,
+}
+
+impl FocusInput {
+    pub fn new(action: FocusAction, target: &Element) -> Self {
+        Self {
+            action,
+            target: target.clone(),
+            cause: 0,
+        }
+    }
+
+    /// Sets the raw cause of the focus change, e.g. `FOCUS_CMD_TYPE` for [`FocusAction::AdvanceRequest`].
+    pub fn with_cause(mut self, cause: u32) -> Self {
+        self.cause = cause;
+        self
+    }
+}